@@ -0,0 +1,143 @@
+use std::{
+    env, fs,
+    io::{self, IsTerminal, Read, Write},
+    process::{Command, ExitCode, Stdio},
+    thread,
+    time::{Duration, Instant},
+};
+
+use aoc24::days::day15::{player::Player, warehouse, widehouse, Day15};
+use aoc24::read_input;
+use aoc24::solution::Solution;
+
+const USAGE: &str = "usage: day15-play [--wide] [input file]";
+
+/**
+ * Animates the day 15 robot in the terminal. `--wide` plays part 2's warehouse. Without a file,
+ * the day 15 input is used.
+ */
+fn main() -> ExitCode {
+    let mut wide = false;
+    let mut path = None;
+    for arg in env::args().skip(1) {
+        match arg.as_str() {
+            "--wide" | "-w" => wide = true,
+            _ if arg.starts_with('-') || path.is_some() => {
+                eprintln!("{}", USAGE);
+                return ExitCode::FAILURE;
+            }
+            _ => path = Some(arg),
+        }
+    }
+    let input = match path {
+        Some(path) => {
+            fs::read_to_string(&path).map_err(|e| format!("couldn't read {}: {}", path, e))
+        }
+        None => read_input(15).map_err(|e| e.to_string()),
+    };
+    let parsed = input.and_then(|input| Day15.parse(&input).map_err(|e| e.to_string()));
+    let (layout, directions) = match parsed {
+        Ok(parsed) => parsed,
+        Err(e) => {
+            eprintln!("{}", e);
+            return ExitCode::FAILURE;
+        }
+    };
+    let world = if wide {
+        widehouse(&layout)
+    } else {
+        warehouse(&layout)
+    };
+    let mut player = Player::new(world, &directions);
+
+    if !io::stdin().is_terminal() {
+        // nothing to read keys from, so just play it through, only pausing between frames if
+        // someone is watching
+        let watched = io::stdout().is_terminal();
+        player.key(' ');
+        while player.is_playing() {
+            player.tick();
+            print!("{}", player.frame(watched));
+            if watched {
+                thread::sleep(Duration::from_millis(player.delay_ms()));
+            }
+        }
+        return ExitCode::SUCCESS;
+    }
+
+    let _terminal = RawTerminal::enter();
+    play(&mut player);
+    ExitCode::SUCCESS
+}
+
+/**
+ * Keys arrive one at a time without waiting for enter, reads never block, and Ctrl-C comes
+ * through as a key instead of killing us. Puts the terminal back how it was when dropped, so a
+ * panic doesn't leave the shell without echo or a cursor either.
+ */
+struct RawTerminal {
+    saved: Option<String>,
+}
+
+impl RawTerminal {
+    fn enter() -> Self {
+        let saved = stty(&["-g"]);
+        stty(&["-icanon", "-echo", "-isig", "min", "0", "time", "0"]);
+        print!("\x1b[?25l");
+        RawTerminal { saved }
+    }
+}
+
+impl Drop for RawTerminal {
+    fn drop(&mut self) {
+        print!("\x1b[?25h");
+        let _ = io::stdout().flush();
+        if let Some(saved) = &self.saved {
+            stty(&[saved.trim()]);
+        }
+    }
+}
+
+fn play(player: &mut Player) {
+    let mut stdin = io::stdin();
+    let mut buf = [0; 16];
+    let mut last_tick = Instant::now();
+    let mut redraw = true;
+    loop {
+        if redraw {
+            print!("{}", player.frame(true));
+            io::stdout().flush().unwrap();
+            redraw = false;
+        }
+        // poll often so keys feel immediate even at slow speeds
+        thread::sleep(Duration::from_millis(player.delay_ms().min(10)));
+
+        let read = stdin.read(&mut buf).unwrap_or(0);
+        for &byte in &buf[..read] {
+            if !player.key(byte as char) {
+                return;
+            }
+            redraw = true;
+        }
+        if player.is_playing() && last_tick.elapsed() >= Duration::from_millis(player.delay_ms()) {
+            player.tick();
+            last_tick = Instant::now();
+            redraw = true;
+        }
+    }
+}
+
+/**
+ * Runs stty on our terminal, returning what it printed.
+ */
+fn stty(args: &[&str]) -> Option<String> {
+    let output = Command::new("stty")
+        .args(args)
+        .stdin(Stdio::inherit())
+        .output()
+        .ok()?;
+    output
+        .status
+        .success()
+        .then(|| String::from_utf8_lossy(&output.stdout).into_owned())
+}
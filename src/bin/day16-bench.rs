@@ -0,0 +1,61 @@
+use std::{env, fs, process::ExitCode};
+
+use aoc24::days::day16::{
+    bench::{report, tiled},
+    Maze,
+};
+use aoc24::{read_input, Grid};
+
+const USAGE: &str = "\
+usage: day16-bench [--tiles <n>...] [input file]
+Times Dijkstra, A* and bidirectional Dijkstra on the maze, then on n by n copies of it joined
+together (by default 2 and 4). Without an input file, the day 16 input is used.";
+
+/**
+ * Compares the day 16 searches: answers, states settled and wall time.
+ */
+fn main() -> ExitCode {
+    match run(env::args().skip(1).collect()) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("{}", e);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn run(args: Vec<String>) -> Result<(), String> {
+    let (mut tiles, mut path) = (Vec::new(), None);
+    let mut iter = args.into_iter();
+    while let Some(arg) = iter.next() {
+        match arg.as_str() {
+            "--tiles" | "-t" => {
+                let n = iter.next().ok_or(USAGE.to_string())?;
+                match n.parse::<usize>() {
+                    Ok(n) if n > 0 => tiles.push(n),
+                    _ => return Err(format!("bad tile count {}\n{}", n, USAGE)),
+                }
+            }
+            _ if arg.starts_with('-') || path.is_some() => return Err(USAGE.to_string()),
+            _ => path = Some(arg),
+        }
+    }
+    if tiles.is_empty() {
+        tiles = vec![2, 4];
+    }
+
+    let input = match path {
+        Some(path) => {
+            fs::read_to_string(&path).map_err(|e| format!("couldn't read {}: {}", path, e))?
+        }
+        None => read_input(16).map_err(|e| e.to_string())?,
+    };
+    let grid = Grid::parse(input.trim_end());
+    let maze = Maze::new(grid.clone()).map_err(|e| e.to_string())?;
+    println!("{}", report("input", &maze));
+    for n in tiles {
+        let maze = Maze::new(tiled(&grid, n)).map_err(|e| e.to_string())?;
+        println!("{}", report(&format!("{}x{} copies", n, n), &maze));
+    }
+    Ok(())
+}
@@ -0,0 +1,103 @@
+use std::{
+    env, fs,
+    io::{self, Write},
+    process::ExitCode,
+};
+
+use aoc24::days::day16::{
+    render::{Analysis, Layer},
+    Costs, Maze,
+};
+use aoc24::{read_input, Direction, Grid};
+
+const USAGE: &str = "\
+usage: day16-render [--format text|ansi|ppm|svg] [--layer arrows|counts|heat] [--scale <n>]
+                    [--costs <forward,left,right[,u-turn[,backward]]>] [--facing <^>v<>]
+                    [--output <file>] [input file]
+Text and ANSI show one layer; the images show all three. Costs default to 1,1000,1000 (`-` leaves
+a move out) and the reindeer starts facing east. Without an input file, the day 16 input is used.
+Without --output, the rendering goes to stdout.";
+
+/**
+ * Draws the reindeer maze's best paths: which way they go, how many pass through each tile, and a
+ * heatmap of the distance from the start.
+ */
+fn main() -> ExitCode {
+    match run(env::args().skip(1).collect()) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("{}", e);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn run(args: Vec<String>) -> Result<(), String> {
+    let (mut format, mut layer, mut scale) = ("text".to_string(), Layer::Arrows, None);
+    let (mut costs, mut facing) = (Costs::PUZZLE, Direction::Right);
+    let (mut output, mut path) = (None, None);
+    let mut iter = args.into_iter();
+    while let Some(arg) = iter.next() {
+        let mut value = || {
+            iter.next()
+                .ok_or(format!("{} needs a value\n{}", arg, USAGE))
+        };
+        match arg.as_str() {
+            "--format" | "-f" => format = value()?,
+            "--layer" | "-l" => {
+                layer = match value()?.as_str() {
+                    "arrows" => Layer::Arrows,
+                    "counts" => Layer::Counts,
+                    "heat" => Layer::Heat,
+                    other => return Err(format!("unknown layer {}\n{}", other, USAGE)),
+                }
+            }
+            "--scale" | "-s" => {
+                let n = value()?;
+                scale = Some(n.parse::<usize>().map_err(|_| format!("bad scale {}", n))?);
+            }
+            "--costs" | "-c" => {
+                let spec = value()?;
+                costs = Costs::parse(&spec).ok_or(format!("bad costs {}\n{}", spec, USAGE))?;
+            }
+            "--facing" => {
+                let arrow = value()?;
+                facing = match arrow.chars().collect::<Vec<_>>().as_slice() {
+                    [c] => Direction::from_arrow(*c),
+                    _ => None,
+                }
+                .ok_or(format!("--facing expects one of ^>v<, not {}", arrow))?;
+            }
+            "--output" | "-o" => output = Some(value()?),
+            "-h" | "--help" => return Err(USAGE.to_string()),
+            _ if arg.starts_with('-') || path.is_some() => return Err(USAGE.to_string()),
+            _ => path = Some(arg),
+        }
+    }
+
+    let input = match path {
+        Some(path) => {
+            fs::read_to_string(&path).map_err(|e| format!("couldn't read {}: {}", path, e))?
+        }
+        None => read_input(16).map_err(|e| e.to_string())?,
+    };
+    let maze = Maze::new(Grid::parse(input.trim_end()))
+        .map_err(|e| e.to_string())?
+        .with_costs(costs)
+        .facing(facing);
+    let analysis = Analysis::new(maze.grid(), &maze.survey(), maze.end());
+
+    let rendered = match format.as_str() {
+        "text" => (analysis.text(layer) + "\n").into_bytes(),
+        "ansi" => (analysis.ansi(layer) + "\n").into_bytes(),
+        "ppm" => analysis.ppm(scale.unwrap_or(4)),
+        "svg" => analysis.svg(scale.unwrap_or(8)).into_bytes(),
+        other => return Err(format!("unknown format {}\n{}", other, USAGE)),
+    };
+    match output {
+        Some(file) => {
+            fs::write(&file, rendered).map_err(|e| format!("couldn't write {}: {}", file, e))
+        }
+        None => io::stdout().write_all(&rendered).map_err(|e| e.to_string()),
+    }
+}
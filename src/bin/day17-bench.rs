@@ -0,0 +1,59 @@
+use std::{env, fs, process::ExitCode};
+
+use aoc24::days::day17::{
+    bench::{first_a, report},
+    debugger::parse_value,
+    CPU,
+};
+use aoc24::read_input;
+
+const USAGE: &str = "\
+usage: day17-bench [--runs <n>] [--from <A>] [program file]
+Runs the program n times (default 100000) with A counting up, once with the interpreter and once
+compiled, and checks they agree. A starts from the smallest value that outputs as many values as
+the program is long, unless --from says otherwise. Without a file, the day 17 input is used.";
+
+/**
+ * Times the day 17 interpreter against the compiled program.
+ */
+fn main() -> ExitCode {
+    match run(env::args().skip(1).collect()) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("{}", e);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn run(args: Vec<String>) -> Result<(), String> {
+    let (mut runs, mut from, mut path) = (100_000, None, None);
+    let mut iter = args.into_iter();
+    while let Some(arg) = iter.next() {
+        match arg.as_str() {
+            "--runs" | "-n" | "--from" | "-a" => {
+                let value = iter.next().ok_or(USAGE.to_string())?;
+                let n = parse_value(&value)
+                    .ok_or_else(|| format!("bad value for {}: {}\n{}", arg, value, USAGE))?;
+                match arg.as_str() {
+                    "--runs" | "-n" => runs = n,
+                    _ => from = Some(n),
+                }
+            }
+            _ if arg.starts_with('-') || path.is_some() => return Err(USAGE.to_string()),
+            _ => path = Some(arg),
+        }
+    }
+
+    let input = match path {
+        Some(path) => {
+            fs::read_to_string(&path).map_err(|e| format!("couldn't read {}: {}", path, e))?
+        }
+        None => read_input(17).map_err(|e| e.to_string())?,
+    };
+    let cpu = CPU::parse(&input)
+        .ok_or("expected `Register A: ...` lines, a blank line, then `Program: ...`".to_string())?;
+    let from = from.unwrap_or_else(|| first_a(cpu.program()));
+    print!("{}", report(&cpu, from, runs)?);
+    Ok(())
+}
@@ -0,0 +1,151 @@
+use std::{
+    env, fs,
+    io::{self, BufRead, Write},
+    process::ExitCode,
+};
+
+use aoc24::days::day17::{
+    debugger::{parse_value, Debugger},
+    trace, CPU,
+};
+use aoc24::read_input;
+
+const USAGE: &str = "\
+usage: day17-debug [program file]
+       day17-debug trace [--csv] [--a <A>] [--limit <n>] [program file]
+       day17-debug diff <A> <A> [--limit <n>] [program file]
+Numbers can be written 0o..., 0b... or 0x... too. Without a file, the day 17 input is used.";
+
+/**
+ * Interactive debugger for day17 programs, plus `trace` to dump a run as JSON Lines (or CSV) and
+ * `diff` to find where runs with two values of A part ways.
+ */
+fn main() -> ExitCode {
+    let args: Vec<String> = env::args().skip(1).collect();
+    let result = match args.first().map(|s| s.as_str()) {
+        Some("-h" | "--help") => Err(USAGE.to_string()),
+        Some("trace") => trace_command(&args[1..]),
+        Some("diff") => diff_command(&args[1..]),
+        _ => repl(&args),
+    };
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("{}", e);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn load(path: Option<&String>) -> Result<CPU, String> {
+    let input = match path {
+        None => read_input(17).map_err(|e| e.to_string())?,
+        Some(path) => {
+            fs::read_to_string(path).map_err(|e| format!("couldn't read {}: {}", path, e))?
+        }
+    };
+    CPU::parse(&input)
+        .ok_or("expected `Register A: ...` lines, a blank line, then `Program: ...`".to_string())
+}
+
+fn number(flag: &str, value: Option<&String>) -> Result<usize, String> {
+    value
+        .and_then(|v| parse_value(v))
+        .ok_or(format!("{} expects a number\n{}", flag, USAGE))
+}
+
+/**
+ * Splits out `--limit` and friends, leaving the positional arguments.
+ */
+fn options<'a>(
+    args: &'a [String],
+    mut flag: impl FnMut(&str, &mut dyn Iterator<Item = &'a String>) -> Result<bool, String>,
+) -> Result<Vec<&'a String>, String> {
+    let mut positional = Vec::new();
+    let mut iter = args.iter();
+    while let Some(arg) = iter.next() {
+        if arg.starts_with("--") {
+            if !flag(arg, &mut iter)? {
+                return Err(format!("unknown option {}\n{}", arg, USAGE));
+            }
+        } else {
+            positional.push(arg);
+        }
+    }
+    Ok(positional)
+}
+
+fn trace_command(args: &[String]) -> Result<(), String> {
+    let (mut csv, mut a, mut limit) = (false, None, None);
+    let positional = options(args, |arg, rest| {
+        match arg {
+            "--csv" => csv = true,
+            "--a" => a = Some(number(arg, rest.next())?),
+            "--limit" => limit = Some(number(arg, rest.next())?),
+            _ => return Ok(false),
+        }
+        Ok(true)
+    })?;
+    if positional.len() > 1 {
+        return Err(USAGE.to_string());
+    }
+    let mut cpu = load(positional.first().copied())?;
+    if let Some(a) = a {
+        cpu.set_reg(0, a);
+    }
+
+    let trace = trace::record(&cpu, limit);
+    if csv {
+        print!("{}", trace.to_csv());
+    } else {
+        print!("{}", trace.to_jsonl());
+    }
+    Ok(())
+}
+
+fn diff_command(args: &[String]) -> Result<(), String> {
+    let mut limit = None;
+    let positional = options(args, |arg, rest| {
+        match arg {
+            "--limit" => limit = Some(number(arg, rest.next())?),
+            _ => return Ok(false),
+        }
+        Ok(true)
+    })?;
+    let (left, right, path) = match positional.as_slice() {
+        [left, right] => (left, right, None),
+        [left, right, path] => (left, right, Some(*path)),
+        _ => return Err(USAGE.to_string()),
+    };
+    let (left, right) = (number("diff", Some(left))?, number("diff", Some(right))?);
+    let cpu = load(path)?;
+
+    match trace::diff(&cpu, left, right, limit) {
+        Some(divergence) => print!("{}", divergence),
+        None => println!("the runs take the same path and output the same values"),
+    }
+    Ok(())
+}
+
+fn repl(args: &[String]) -> Result<(), String> {
+    if args.len() > 1 {
+        return Err(USAGE.to_string());
+    }
+    let mut debugger = Debugger::new(load(args.first())?);
+    println!("{}", debugger.status());
+    println!("type `help` for commands");
+    let stdin = io::stdin();
+    let mut lines = stdin.lock().lines();
+    loop {
+        print!("(day17) ");
+        io::stdout().flush().unwrap();
+        let Some(Ok(line)) = lines.next() else {
+            break;
+        };
+        match debugger.command(&line) {
+            Some(reply) => println!("{}", reply),
+            None => break,
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,83 @@
+use std::{env, fs, process::ExitCode};
+
+use aoc24::generate::{falling_bytes, reindeer_maze, MazeOptions};
+
+const USAGE: &str = "\
+usage: gen-input maze [--size <w>x<h>] [--loops <p>] [--dead-ends <p>] [--seed <n>] [--output <file>]
+       gen-input bytes [--size <n>] [--count <n>] [--seed <n>] [--output <file>]
+Makes up a day 16 maze or a day 18 list of falling bytes in the puzzle's own format. The same
+seed always gives the same input. Mazes default to 141x141 like the real thing, with 5% of the
+inner walls knocked out (--loops) and half the dead ends kept (--dead-ends). Bytes default to
+a 71 by 71 memory space and every cell but the two corners.";
+
+/**
+ * Writes bigger inputs than the real ones, for stress testing and benchmarking.
+ */
+fn main() -> ExitCode {
+    match run(env::args().skip(1).collect()) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("{}", e);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn run(args: Vec<String>) -> Result<(), String> {
+    let mut iter = args.into_iter();
+    let kind = iter.next().ok_or(USAGE.to_string())?;
+    let (mut seed, mut output) = (0, None);
+    let mut options = MazeOptions::default();
+    let (mut size, mut count) = (None, None);
+    while let Some(arg) = iter.next() {
+        let value = match arg.as_str() {
+            "--size" | "-s" | "--loops" | "-l" | "--dead-ends" | "-d" | "--count" | "-c"
+            | "--seed" | "-S" | "--output" | "-o" => iter.next().ok_or(USAGE.to_string())?,
+            _ => return Err(USAGE.to_string()),
+        };
+        let bad = || format!("bad value for {}: {}\n{}", arg, value, USAGE);
+        match (kind.as_str(), arg.as_str()) {
+            (_, "--seed" | "-S") => seed = value.parse().map_err(|_| bad())?,
+            (_, "--output" | "-o") => output = Some(value.clone()),
+            ("maze", "--size" | "-s") => {
+                let (w, h) = value.split_once('x').ok_or_else(bad)?;
+                options.width = w.parse().map_err(|_| bad())?;
+                options.height = h.parse().map_err(|_| bad())?;
+            }
+            ("maze", "--loops" | "-l") => {
+                options.loopiness = probability(&value).ok_or_else(bad)?
+            }
+            ("maze", "--dead-ends" | "-d") => {
+                options.dead_ends = probability(&value).ok_or_else(bad)?
+            }
+            ("bytes", "--size" | "-s") => match value.parse::<usize>() {
+                Ok(n) if n >= 2 => size = Some(n),
+                _ => return Err(bad()),
+            },
+            ("bytes", "--count" | "-c") => count = Some(value.parse().map_err(|_| bad())?),
+            _ => return Err(USAGE.to_string()),
+        }
+    }
+
+    let text = match kind.as_str() {
+        "maze" => reindeer_maze(seed, &options),
+        "bytes" => {
+            let size = size.unwrap_or(71);
+            falling_bytes(seed, size, count.unwrap_or(size * size))
+        }
+        _ => return Err(USAGE.to_string()),
+    };
+    match output {
+        Some(path) => {
+            fs::write(&path, text + "\n").map_err(|e| format!("couldn't write {}: {}", path, e))
+        }
+        None => {
+            println!("{}", text);
+            Ok(())
+        }
+    }
+}
+
+fn probability(value: &str) -> Option<f64> {
+    value.parse().ok().filter(|p| (0.0..=1.0).contains(p))
+}
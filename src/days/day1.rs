@@ -0,0 +1,93 @@
+use std::collections::HashMap;
+
+use crate::error::Error;
+use crate::solution::Solution;
+
+pub struct Day1;
+
+impl Solution for Day1 {
+    type Input = (Vec<i32>, Vec<i32>);
+
+    fn day(&self) -> i32 {
+        1
+    }
+
+    fn title(&self) -> &'static str {
+        "Historian Hysteria"
+    }
+
+    fn parse(&self, input: &str) -> Result<Self::Input, Error> {
+        Ok(parse(input))
+    }
+
+    fn part1(&self, input: &Self::Input) -> String {
+        part1(input)
+    }
+
+    fn part2(&self, input: &Self::Input) -> String {
+        part2(input)
+    }
+}
+
+fn parse(input: &str) -> (Vec<i32>, Vec<i32>) {
+    let lines = input.split("\n");
+    let mut locations1: Vec<i32> = Vec::with_capacity(1000);
+    let mut locations2: Vec<i32> = Vec::with_capacity(1000);
+
+    // would be nice to have a function that automatically takes this string input and formats it into arrays.
+    for line in lines {
+        let nums: Vec<&str> = line.split("   ").collect();
+        locations1.push(str::parse::<i32>(nums[0]).unwrap());
+        locations2.push(str::parse::<i32>(nums[1]).unwrap());
+    }
+    (locations1, locations2)
+}
+
+fn part1(lists: &(Vec<i32>, Vec<i32>)) -> String {
+    let mut locations1 = lists.0.clone();
+    let mut locations2 = lists.1.clone();
+
+    locations1.sort();
+    locations2.sort();
+
+    let mut sum: i32 = 0;
+    for (i, item) in locations1.iter().enumerate() {
+        sum += (locations2[i] - item).abs();
+    }
+    format!("{}", sum)
+}
+
+fn part2(lists: &(Vec<i32>, Vec<i32>)) -> String {
+    let mut counter: HashMap<i32, i32> = HashMap::new();
+
+    for location in &lists.1 {
+        counter.entry(*location).or_insert(0);
+        let cur_value = counter[location];
+        counter.insert(*location, cur_value + 1);
+    }
+
+    let mut sum: i32 = 0;
+    for item in lists.0.iter() {
+        if counter.contains_key(item) {
+            sum += item * counter.get(item).unwrap();
+        }
+    }
+    format!("{}", sum)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part1() {
+        let test_input = "3   4\n4   3\n2   5\n1   3\n3   9\n3   3";
+        assert_eq!(part1(&parse(test_input)), "11");
+    }
+
+    #[test]
+    fn test_part2() {
+        let test_input = "3   4\n4   3\n2   5\n1   3\n3   9\n3   3";
+        assert_eq!(part2(&parse(test_input)), "31");
+    }
+}
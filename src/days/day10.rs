@@ -0,0 +1,180 @@
+use std::{
+    collections::{HashMap, HashSet, VecDeque},
+    hash::Hash,
+};
+
+use crate::error::Error;
+use crate::solution::Solution;
+use crate::*;
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    type Input = Grid<i32>;
+
+    fn day(&self) -> i32 {
+        10
+    }
+
+    fn title(&self) -> &'static str {
+        "Hoof It"
+    }
+
+    fn parse(&self, input: &str) -> Result<Self::Input, Error> {
+        Ok(get_grid(input))
+    }
+
+    fn part1(&self, input: &Self::Input) -> String {
+        part1(input)
+    }
+
+    fn part2(&self, input: &Self::Input) -> String {
+        part2(input)
+    }
+}
+
+fn part1(grid: &Grid<i32>) -> String {
+    let trailheads = find_trailheads(grid);
+
+    let mut sum = 0;
+    for trailhead in trailheads {
+        sum += find_peaks(grid, trailhead).len()
+    }
+    sum.to_string()
+}
+
+fn part2(grid: &Grid<i32>) -> String {
+    let trailheads = find_trailheads(grid);
+
+    let mut sum = 0;
+    for trailhead in trailheads {
+        sum += find_unique_trails(grid, trailhead);
+    }
+    sum.to_string()
+}
+
+fn find_unique_trails(grid: &Grid<i32>, start: (usize, usize)) -> usize {
+    let mut visited: HashMap<(usize, usize), usize> = HashMap::new();
+    let mut to_visit_unique: HashSet<(usize, usize)> = HashSet::new();
+    let mut to_visit: VecDeque<(usize, usize)> = VecDeque::new();
+    let mut total_paths: usize = 0;
+
+    to_visit_unique.insert(start);
+    to_visit.push_back(start);
+    visited.insert(start, 1);
+
+    while !to_visit.is_empty() {
+        // pop from front of queue
+        let current = to_visit.pop_front().unwrap();
+        to_visit_unique.remove(&current);
+        // if at peak, add current_num_paths to total_paths
+        let elevation = grid[current];
+        let current_num_paths = *visited.get(&current).unwrap();
+        if elevation == 9 {
+            total_paths += current_num_paths;
+        }
+        // check all neighboring squares in the grid
+        for new_location in grid.neighbours4(current) {
+            // if next square is higher
+            if grid[new_location] == elevation + 1 {
+                // increment visit counter
+                increment_counter(&mut visited, &new_location, current_num_paths);
+                // push new location to queue if it isn't in there already
+                if !to_visit_unique.contains(&new_location) {
+                    to_visit.push_back(new_location);
+                    to_visit_unique.insert(new_location);
+                }
+            }
+        }
+    }
+    total_paths
+}
+
+fn increment_counter<T: Eq + Hash + Clone>(
+    counter: &mut HashMap<T, usize>,
+    key: &T,
+    amount: usize,
+) -> usize {
+    if !counter.contains_key(key) {
+        counter.insert(key.clone(), amount);
+        amount
+    } else {
+        let prev_value = *counter.get(key).unwrap();
+        counter.insert(key.clone(), prev_value + amount);
+        prev_value + amount
+    }
+}
+
+/**
+* dfs through grid, find # peaks reachable
+*/
+fn find_peaks(grid: &Grid<i32>, start: (usize, usize)) -> HashSet<(usize, usize)> {
+    let mut visited: HashSet<(usize, usize)> = HashSet::new();
+    let mut to_visit: HashSet<(usize, usize)> = HashSet::new();
+    let mut peaks: HashSet<(usize, usize)> = HashSet::new();
+    to_visit.insert(start);
+
+    while !to_visit.is_empty() {
+        // set pop
+        let current = *to_visit.iter().next().unwrap();
+        to_visit.remove(&current);
+
+        let elevation = grid[current];
+        if elevation == 9 {
+            peaks.insert(current);
+        }
+
+        for new_location in grid.neighbours4(current) {
+            if !visited.contains(&new_location) && grid[new_location] == elevation + 1 {
+                to_visit.insert(new_location);
+            }
+        }
+        visited.insert(current);
+    }
+    peaks
+}
+
+fn find_trailheads(grid: &Grid<i32>) -> Vec<(usize, usize)> {
+    grid.find_all(&0).collect()
+}
+
+fn get_grid(input: &str) -> Grid<i32> {
+    Grid::from_rows(parse_row_major::<i32>(input, ""))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    static TEST_INPUT: &str = "89010123
+78121874
+87430965
+96549874
+45678903
+32019012
+01329801
+10456732";
+
+    static TEST_INPUT_2: &str = ".....0.
+..4321.
+..5..2.
+..6543.
+..7..4.
+..8765.
+..9....";
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(part1(&get_grid(TEST_INPUT)), "36");
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(part2(&get_grid(TEST_INPUT)), "81");
+    }
+
+    #[test]
+    fn test_part2_simple() {
+        assert_eq!(part2(&get_grid(TEST_INPUT_2)), "6");
+    }
+}
@@ -1,19 +1,43 @@
 use std::{collections::HashMap, hash::Hash};
 
-use aoc24::*;
+use crate::error::Error;
+use crate::solution::Solution;
+use crate::*;
 
-fn main() {
-    let binding = read_input(11);
-    let input = binding.as_str();
-    println!("Part 1: {}", part1(&input));
-    println!("Part 2: {}", part2(&input));
+pub struct Day11;
+
+impl Solution for Day11 {
+    type Input = Vec<usize>;
+
+    fn day(&self) -> i32 {
+        11
+    }
+
+    fn title(&self) -> &'static str {
+        "Plutonian Pebbles"
+    }
+
+    fn parse(&self, input: &str) -> Result<Self::Input, Error> {
+        Ok(parse(input))
+    }
+
+    fn part1(&self, input: &Self::Input) -> String {
+        part1(input)
+    }
+
+    fn part2(&self, input: &Self::Input) -> String {
+        part2(input)
+    }
+}
+
+fn parse(input: &str) -> Vec<usize> {
+    parse_row_major::<usize>(input, " ").swap_remove(0)
 }
 
-fn part1(input: &str) -> String {
-    let stones_raw = parse_row_major::<usize>(input, " ");
+fn part1(stones_raw: &Vec<usize>) -> String {
     let mut stones: HashMap<usize, usize> = HashMap::new();
 
-    for stone in &stones_raw[0] {
+    for stone in stones_raw {
         stones.insert(*stone, 1);
     }
 
@@ -28,11 +52,10 @@ fn part1(input: &str) -> String {
     //"".to_string()
 }
 
-fn part2(input: &str) -> String {
-    let stones_raw = parse_row_major::<usize>(input, " ");
+fn part2(stones_raw: &Vec<usize>) -> String {
     let mut stones: HashMap<usize, usize> = HashMap::new();
 
-    for stone in &stones_raw[0] {
+    for stone in stones_raw {
         stones.insert(*stone, 1);
     }
 
@@ -47,20 +70,12 @@ fn part2(input: &str) -> String {
     //"".to_string()
 }
 
-fn display_stones(stones: &HashMap<usize, usize>) -> String {
-    let mut ret = String::new();
-    for stone in stones {
-        ret.push_str(format!("{}: {}", *stone.0, *stone.1).as_str());
-    }
-    ret
-}
-
 fn convert_stone_counter(stones: &HashMap<usize, usize>) -> HashMap<usize, usize> {
     let mut new_stones: HashMap<usize, usize> = HashMap::new();
     for stone in stones {
         if *stone.0 == 0 {
             increment_counter(&mut new_stones, &1, *stone.1);
-        } else if num_digits(*stone.0) % 2 == 0 {
+        } else if num_digits(*stone.0).is_multiple_of(2) {
             let new_nums = split_number(*stone.0);
             increment_counter(&mut new_stones, &new_nums.0, *stone.1);
             increment_counter(&mut new_stones, &new_nums.1, *stone.1);
@@ -88,7 +103,7 @@ fn split_number(x: usize) -> (usize, usize) {
         power *= 10;
     }
 
-    return (x / power, x % power);
+    (x / power, x % power)
 }
 
 fn num_digits(x: usize) -> usize {
@@ -102,7 +117,7 @@ fn num_digits(x: usize) -> usize {
         digits += 1;
         temp /= 10;
     }
-    return digits;
+    digits
 }
 
 fn increment_counter<T: Eq + Hash + Clone>(
@@ -110,11 +125,11 @@ fn increment_counter<T: Eq + Hash + Clone>(
     key: &T,
     amount: usize,
 ) -> usize {
-    if !counter.contains_key(&key) {
+    if !counter.contains_key(key) {
         counter.insert(key.clone(), amount);
         amount
     } else {
-        let prev_value = counter.get(&key).unwrap().clone();
+        let prev_value = *counter.get(key).unwrap();
         counter.insert(key.clone(), prev_value + amount);
         prev_value + amount
     }
@@ -126,9 +141,17 @@ mod tests {
 
     static TEST_INPUT: &str = "125 17";
 
+    fn display_stones(stones: &HashMap<usize, usize>) -> String {
+        let mut ret = String::new();
+        for stone in stones {
+            ret.push_str(format!("{}: {}", *stone.0, *stone.1).as_str());
+        }
+        ret
+    }
+
     #[test]
     fn test_part1() {
-        assert_eq!(part1(TEST_INPUT), "55312");
+        assert_eq!(part1(&parse(TEST_INPUT)), "55312");
     }
 
     #[test]
@@ -160,6 +183,6 @@ mod tests {
 
     #[test]
     fn test_part2() {
-        assert_eq!(part2(TEST_INPUT), "0");
+        assert_eq!(part2(&parse(TEST_INPUT)), "65601038650482");
     }
 }
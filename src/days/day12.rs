@@ -0,0 +1,212 @@
+use std::collections::HashSet;
+
+use crate::error::Error;
+use crate::solution::Solution;
+use crate::*;
+
+pub struct Day12;
+
+// a fence sits on the side of a plot, facing out of its region
+type Fence = (Point, Direction);
+
+impl Solution for Day12 {
+    type Input = Grid<char>;
+
+    fn day(&self) -> i32 {
+        12
+    }
+
+    fn title(&self) -> &'static str {
+        "Garden Groups"
+    }
+
+    fn parse(&self, input: &str) -> Result<Self::Input, Error> {
+        Ok(Grid::parse(input))
+    }
+
+    fn part1(&self, input: &Self::Input) -> String {
+        part1(input)
+    }
+
+    fn part2(&self, input: &Self::Input) -> String {
+        part2(input)
+    }
+}
+
+fn part1(grid: &Grid<char>) -> String {
+    let mut visited = HashSet::new();
+    let mut sum: usize = 0;
+
+    for cur_loc in grid.positions() {
+        if !visited.contains(&cur_loc) {
+            let ap = bfs_part1(grid, cur_loc, &mut visited);
+            sum += ap.0 * ap.1;
+        }
+    }
+
+    sum.to_string()
+}
+
+/**
+ * Now, we only count the number of sides
+ * This means that contiguous, aligned edges count as a single side
+ * My current idea is to keep track of fence sides, merging them if we find a contiguous fence
+ * In this case, I belive we can simply keep track of all fence edges, and then the number of
+ * contiguous fences in a single variable we decrement every time we coalesce a fence edge.
+ */
+fn part2(grid: &Grid<char>) -> String {
+    let mut visited = HashSet::new();
+    let mut sum: usize = 0;
+
+    for cur_loc in grid.positions() {
+        if !visited.contains(&cur_loc) {
+            let ap = bfs_part2(grid, cur_loc, &mut visited);
+            sum += ap.0 * ap.1;
+        }
+    }
+
+    sum.to_string()
+}
+
+fn bfs_part2(
+    grid: &Grid<char>,
+    start: (usize, usize),
+    visited: &mut HashSet<(usize, usize)>,
+) -> (usize, usize) {
+    let mut to_visit = HashSet::new();
+    to_visit.insert(start);
+
+    let mut area: usize = 0;
+    let mut num_sides: usize = 0;
+    let mut fences = HashSet::new();
+
+    while !to_visit.is_empty() {
+        let cur_loc = *to_visit.iter().next().unwrap();
+        to_visit.remove(&cur_loc);
+        let (neighbors, edges) = get_neighbors_and_edges(grid, cur_loc);
+
+        area += 1;
+        for neighbor in neighbors {
+            if !visited.contains(&neighbor) {
+                to_visit.insert(neighbor);
+            }
+        }
+        for edge in edges {
+            coalesce_fences(&mut fences, edge, &mut num_sides);
+        }
+
+        visited.insert(cur_loc);
+    }
+
+    (area, num_sides)
+}
+
+/**
+* A clean way to do this would be to return the delta to be added to sides, but then that would
+* involve weird conversions between usize and an i type which I don't like.
+* Oh well, we're already modifying the hashset anyway
+*/
+fn coalesce_fences(fences: &mut HashSet<Fence>, new_fence: Fence, num_sides: &mut usize) {
+    let (fence_loc, facing) = new_fence;
+    fences.insert(new_fence);
+    *num_sides += 1;
+    let left_fence_loc = fence_loc + facing.turn_left();
+    let right_fence_loc = fence_loc + facing.turn_right();
+
+    if fences.contains(&(left_fence_loc, facing)) {
+        *num_sides -= 1
+    }
+    if fences.contains(&(right_fence_loc, facing)) {
+        *num_sides -= 1
+    }
+}
+
+/**
+ * Returns: Area, Perimeter
+ */
+fn bfs_part1(
+    grid: &Grid<char>,
+    start: (usize, usize),
+    visited: &mut HashSet<(usize, usize)>,
+) -> (usize, usize) {
+    let mut to_visit = HashSet::new();
+    to_visit.insert(start);
+
+    let mut area: usize = 0;
+    let mut perimeter: usize = 0;
+    while !to_visit.is_empty() {
+        let cur_loc = *to_visit.iter().next().unwrap();
+        to_visit.remove(&cur_loc);
+        let neighbors = get_neighbors(grid, cur_loc);
+
+        area += 1;
+        perimeter += 4 - neighbors.len();
+        for neighbor in neighbors {
+            if !visited.contains(&neighbor) {
+                to_visit.insert(neighbor);
+            }
+        }
+
+        visited.insert(cur_loc);
+    }
+
+    (area, perimeter)
+}
+
+/**
+ * Precondition: loc is in bounds of grid
+ * Perhaps it's time to start using OOP
+ */
+fn get_neighbors_and_edges(
+    grid: &Grid<char>,
+    loc: (usize, usize),
+) -> (Vec<(usize, usize)>, Vec<Fence>) {
+    let mut neighbors = Vec::new();
+    let mut edges = Vec::new();
+    let cur_crop = grid[loc];
+    for direction in Direction::ALL {
+        // fences can sit just off the grid, so they're kept as signed points
+        let newloc = Point::from(loc) + direction;
+        match grid.locate(newloc) {
+            Some(neighbor) if grid[neighbor] == cur_crop => neighbors.push(neighbor),
+            _ => edges.push((newloc, direction)),
+        }
+    }
+    (neighbors, edges)
+}
+
+/**
+ * Precondition: loc is in bounds of grid
+ */
+fn get_neighbors(grid: &Grid<char>, loc: (usize, usize)) -> Vec<(usize, usize)> {
+    let cur_crop = grid[loc];
+    grid.neighbours4(loc)
+        .filter(|&newloc| grid[newloc] == cur_crop)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    static TEST_INPUT: &str = "RRRRIICCFF
+RRRRIICCCF
+VVRRRCCFFF
+VVRCCCJFFF
+VVVVCJJCFE
+VVIVCCJJEE
+VVIIICJJEE
+MIIIIIJJEE
+MIIISIJEEE
+MMMISSJEEE";
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(part1(&Grid::parse(TEST_INPUT)), "1930");
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(part2(&Grid::parse(TEST_INPUT)), "1206");
+    }
+}
@@ -1,49 +1,80 @@
 use core::fmt;
 
-use aoc24::*;
-use regex::Regex;
-
-fn main() {
-    let binding = read_input(13);
-    let input = binding.as_str();
-
-    let uh_oh_1 = uh_oh_count(&create_matrices(input));
-    let uh_oh_2 = uh_oh_count(&create_matrices_2(input));
-    println!("uh oh counts, part 1: {}, {}", uh_oh_1.0, uh_oh_1.1);
-    println!("Part 1: {}", part1(&input));
-    println!("uh oh counts, part 2: {}, {}", uh_oh_2.0, uh_oh_2.1);
-    println!("Part 2: {}", part2(&input));
+use crate::error::Error;
+use crate::solution::{Solution, Variant};
+use crate::*;
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    type Input = Vec<AugMat>;
+
+    fn day(&self) -> i32 {
+        13
+    }
+
+    fn title(&self) -> &'static str {
+        "Claw Contraption"
+    }
+
+    fn parse(&self, input: &str) -> Result<Self::Input, Error> {
+        create_matrices(input)
+    }
+
+    fn part1(&self, input: &Self::Input) -> String {
+        part1(input)
+    }
+
+    fn part2(&self, input: &Self::Input) -> String {
+        part2(input)
+    }
+
+    fn variants(&self) -> Vec<Variant<Self::Input>> {
+        vec![Variant::new("uh-oh", |matrices| uh_oh_report(matrices))]
+    }
 }
 
-fn part1(input: &str) -> String {
-    let matrices = create_matrices(input);
+fn part1(matrices: &[AugMat]) -> String {
     let sum = matrices.iter().fold(0, |acc, mat| acc + mat.token_cost());
     sum.to_string()
 }
 
-fn part2(input: &str) -> String {
-    let matrices = create_matrices_2(input);
+fn part2(matrices: &[AugMat]) -> String {
+    let matrices = create_matrices_2(matrices);
     let sum = matrices.iter().fold(0, |acc, mat| acc + mat.token_cost());
     sum.to_string()
 }
 
-fn create_matrices(input: &str) -> Vec<AugMat> {
-    let sections = split_sections(input);
-    sections.iter().map(|s| AugMat::parse_section(s)).collect()
+fn create_matrices(input: &str) -> Result<Vec<AugMat>, Error> {
+    let pairs = get_xy_pairs(input)?;
+    if !pairs.len().is_multiple_of(3) {
+        return Err(Error::BadInput(
+            "each machine needs two buttons and a prize".into(),
+        ));
+    }
+    Ok(pairs
+        .chunks(3)
+        .map(|machine| AugMat::new(machine[0], machine[1], machine[2]))
+        .collect())
 }
 
-fn create_matrices_2(input: &str) -> Vec<AugMat> {
-    let sections = split_sections(input);
-    sections
-        .iter()
-        .map(|s| AugMat::parse_section_2(s))
-        .collect()
+fn create_matrices_2(matrices: &[AugMat]) -> Vec<AugMat> {
+    matrices.iter().map(|m| m.far_prize()).collect()
+}
+
+fn uh_oh_report(matrices: &[AugMat]) -> String {
+    let uh_oh_1 = uh_oh_count(matrices);
+    let uh_oh_2 = uh_oh_count(&create_matrices_2(matrices));
+    format!(
+        "part 1: {}, {}; part 2: {}, {}",
+        uh_oh_1.0, uh_oh_1.1, uh_oh_2.0, uh_oh_2.1
+    )
 }
 
 /**
  * Check if we're gonna have to do ugly integer programming
  */
-fn uh_oh_count(matrices: &Vec<AugMat>) -> (i64, i64) {
+fn uh_oh_count(matrices: &[AugMat]) -> (i64, i64) {
     let od_count = matrices.iter().fold(0, |acc, mat| {
         if mat.check_overdetermined().0 {
             acc + 1
@@ -63,7 +94,8 @@ fn uh_oh_count(matrices: &Vec<AugMat>) -> (i64, i64) {
     (od_count, lp_count)
 }
 
-struct AugMat {
+#[derive(Debug)]
+pub struct AugMat {
     v1: (i64, i64),
     v2: (i64, i64),
     target: (i64, i64),
@@ -74,17 +106,15 @@ impl AugMat {
         AugMat { v1, v2, target }
     }
 
-    fn parse_section(section: &str) -> Self {
-        let vecs: Vec<(i64, i64)> = section.lines().map(|l| get_xy_pair(l)).collect();
-        AugMat::new(vecs[0], vecs[1], vecs[2])
-    }
-
-    fn parse_section_2(section: &str) -> Self {
-        let vecs: Vec<(i64, i64)> = section.lines().map(|l| get_xy_pair(l)).collect();
+    // the same machine, with the prize moved out by the part 2 unit conversion error
+    fn far_prize(&self) -> Self {
         AugMat::new(
-            vecs[0],
-            vecs[1],
-            (vecs[2].0 + 10000000000000, vecs[2].1 + 10000000000000),
+            self.v1,
+            self.v2,
+            (
+                self.target.0 + 10000000000000,
+                self.target.1 + 10000000000000,
+            ),
         )
     }
 
@@ -92,10 +122,10 @@ impl AugMat {
      * Check if we need to do integer programming
      */
     fn check_overdetermined(&self) -> (bool, bool) {
-        return (
+        (
             self.det() == 0,
             self.det() == 0 && self.dx() == 0 && self.dy() == 0,
-        );
+        )
     }
 
     fn det(&self) -> i64 {
@@ -131,11 +161,9 @@ impl AugMat {
     }
 
     fn token_cost(&self) -> i64 {
-        let int_soln = self.cramer_int();
-        if int_soln.is_some() {
-            return int_soln.unwrap().0 * 3 + int_soln.unwrap().1;
-        } else {
-            return 0;
+        match self.cramer_int() {
+            Some((a, b)) => a * 3 + b,
+            None => 0,
         }
     }
 }
@@ -150,18 +178,14 @@ impl fmt::Display for AugMat {
     }
 }
 
-fn get_xy_pair(line: &str) -> (i64, i64) {
-    let re = Regex::new(r".*X.(?<a>\d+).*Y.(?<b>\d+)").unwrap();
-    let ret = re
-        .captures_iter(line)
-        .map(|caps| {
-            let a = caps.name("a").unwrap().as_str().parse::<i64>().unwrap();
-            let b = caps.name("b").unwrap().as_str().parse::<i64>().unwrap();
-            (a, b)
-        })
-        .nth(0)
-        .unwrap();
-    ret
+/**
+ * The X and Y of every button and prize line, skipping the blank lines between machines.
+ */
+fn get_xy_pairs(input: &str) -> Result<Vec<(i64, i64)>, ParseError> {
+    Ok(parse_int_lines::<i64, 2>(input)?
+        .into_iter()
+        .map(|[x, y]| (x, y))
+        .collect())
 }
 
 #[cfg(test)]
@@ -186,12 +210,12 @@ Prize: X=18641, Y=10279";
 
     #[test]
     fn test_part1() {
-        assert_eq!(part1(TEST_INPUT), "0");
+        assert_eq!(part1(&create_matrices(TEST_INPUT).unwrap()), "480");
     }
 
     #[test]
     fn test_part2() {
-        assert_eq!(part2(TEST_INPUT), "0");
+        assert_eq!(part2(&create_matrices(TEST_INPUT).unwrap()), "875318608908");
     }
 
     #[test]
@@ -199,10 +223,28 @@ Prize: X=18641, Y=10279";
         let input = "Button A: X+94, Y+34
 Button B: X+22, Y+67
 Prize: X=8400, Y=5400";
-        let am = AugMat::parse_section(input);
+        let am = &create_matrices(input).unwrap()[0];
         assert_eq!(format!("{}", am), "[94 22 | 8400]\n[34 67 | 5400]")
     }
 
+    #[test]
+    fn test_parse_errors() {
+        let error = Day13
+            .parse("Button A: X+94, Y+34\nButton B: X+22\n")
+            .unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "bad input: line 2, column 15: expected 2 numbers, found 1 in \"Button B: X+22\""
+        );
+        let error = Day13
+            .parse("Button A: X+94, Y+34\nButton B: X+22, Y+67\n")
+            .unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "bad input: each machine needs two buttons and a prize"
+        );
+    }
+
     #[test]
     fn test_overdet() {
         let am1 = AugMat::new((1, 2), (2, 4), (4, 8));
@@ -0,0 +1,261 @@
+use core::f64;
+
+use crate::error::{Error, ParseError};
+use crate::parse_int_lines;
+use crate::solution::Solution;
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    type Input = Vec<Robot>;
+
+    fn day(&self) -> i32 {
+        14
+    }
+
+    fn title(&self) -> &'static str {
+        "Restroom Redoubt"
+    }
+
+    fn parse(&self, input: &str) -> Result<Self::Input, Error> {
+        Ok(get_robots(input)?)
+    }
+
+    fn part1(&self, input: &Self::Input) -> String {
+        part1(input)
+    }
+
+    fn part2(&self, input: &Self::Input) -> String {
+        part2(input)
+    }
+}
+
+fn part1(robots: &[Robot]) -> String {
+    static BOUNDS: (i32, i32) = (103, 101);
+    part1_bounds(robots, BOUNDS)
+}
+
+fn part2(robots: &[Robot]) -> String {
+    static BOUNDS: (i32, i32) = (103, 101);
+    part2_bounds(robots, BOUNDS, 10000).to_string()
+}
+
+// the tree shows up as the step where the robots are most tightly clustered
+fn part2_bounds(robots: &[Robot], bounds: (i32, i32), max_steps: i32) -> i32 {
+    let mut robots = robots.to_vec();
+    let mut steps = 0;
+    let grid = get_robot_grid(&robots, bounds);
+    let mut min_variance = grid_variance(&grid);
+    let mut min_step = 0;
+    while steps < max_steps {
+        let grid = get_robot_grid(&robots, bounds);
+
+        let variance = grid_variance(&grid);
+        if variance < min_variance {
+            min_variance = variance;
+            min_step = steps;
+        }
+
+        for robot in &mut robots {
+            robot.simulate(1, bounds);
+        }
+        steps += 1;
+    }
+    min_step
+}
+
+fn part1_bounds(robots: &[Robot], bounds: (i32, i32)) -> String {
+    let (quadrant_counts, _) = part1_robot_sim(robots, bounds);
+    let danger = quadrant_counts.iter().product::<i32>();
+    danger.to_string()
+}
+
+fn part1_robot_sim(robots: &[Robot], bounds: (i32, i32)) -> ([i32; 4], Vec<Robot>) {
+    let mut robots = robots.to_vec();
+    let mut quadrant_counts = [0, 0, 0, 0];
+    for robot in &mut robots {
+        robot.simulate(100, bounds);
+        if let Some(quadrant) = robot.quadrant(bounds) {
+            quadrant_counts[quadrant] += 1;
+        }
+    }
+    (quadrant_counts, robots)
+}
+
+fn grid_variance(grid: &[Vec<usize>]) -> f64 {
+    let mean = grid_mean(grid);
+    let mut dist: f64 = 0.0;
+    let mut count = 0;
+    for (i, row) in grid.iter().enumerate() {
+        for (j, &cell) in row.iter().enumerate() {
+            if cell > 0 {
+                dist += pyth_normsq(mean, (i as f64, j as f64));
+                count += 1;
+            }
+        }
+    }
+    dist / (count as f64)
+}
+
+fn pyth_normsq(a: (f64, f64), b: (f64, f64)) -> f64 {
+    (a.0 - b.0).powi(2) + (a.1 - b.1).powi(2)
+}
+
+fn grid_mean(grid: &[Vec<usize>]) -> (f64, f64) {
+    let mut sumy = 0;
+    let mut sumx = 0;
+    let mut total = 0;
+    for (i, row) in grid.iter().enumerate() {
+        for (j, &cell) in row.iter().enumerate() {
+            if cell > 0 {
+                sumx += i;
+                sumy += j;
+                total += 1;
+            }
+        }
+    }
+    (sumx as f64 / total as f64, sumy as f64 / total as f64)
+}
+
+fn get_robot_grid(robots: &Vec<Robot>, bounds: (i32, i32)) -> Vec<Vec<usize>> {
+    let mut grid = vec![vec![0; bounds.1 as usize]; bounds.0 as usize];
+
+    for robot in robots {
+        grid[robot.position.0 as usize][robot.position.1 as usize] += 1;
+    }
+
+    grid
+}
+
+fn get_robots(input: &str) -> Result<Vec<Robot>, ParseError> {
+    Ok(parse_int_lines::<i32, 4>(input)?
+        .into_iter()
+        .map(Robot::from_numbers)
+        .collect())
+}
+
+#[derive(Clone)]
+pub struct Robot {
+    position: (i32, i32),
+    velocity: (i32, i32),
+}
+
+impl Robot {
+    // `p=x,y v=x,y`, kept as (y, x) like everything else
+    fn from_numbers([px, py, vx, vy]: [i32; 4]) -> Self {
+        Robot {
+            position: (py, px),
+            velocity: (vy, vx),
+        }
+    }
+
+    // returns the position after `steps` steps
+    fn simulate(&mut self, steps: i32, bounds: (i32, i32)) -> (i32, i32) {
+        let delta = (steps * self.velocity.0, steps * self.velocity.1);
+        let nowrap = (self.position.0 + delta.0, self.position.1 + delta.1);
+        self.position = (nowrap.0.rem_euclid(bounds.0), nowrap.1.rem_euclid(bounds.1));
+        self.position
+    }
+
+    fn quadrant(&self, bounds: (i32, i32)) -> Option<usize> {
+        let lims = (bounds.0 / 2, bounds.1 / 2);
+        if self.position.0 < lims.0 && self.position.1 < lims.1 {
+            return Some(0);
+        } else if self.position.0 > lims.0 && self.position.1 < lims.1 {
+            return Some(1);
+        } else if self.position.0 > lims.0 && self.position.1 > lims.1 {
+            return Some(2);
+        } else if self.position.0 < lims.0 && self.position.1 > lims.1 {
+            return Some(3);
+        }
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    static TEST_INPUT: &str = "p=0,4 v=3,-3
+p=6,3 v=-1,-3
+p=10,3 v=-1,2
+p=2,0 v=2,-1
+p=0,0 v=1,3
+p=3,0 v=-2,-2
+p=7,6 v=-1,-3
+p=3,0 v=-1,-2
+p=9,3 v=2,3
+p=7,3 v=-1,2
+p=2,4 v=2,-3
+p=9,5 v=-3,-3";
+
+    fn part1_map(robots: &[Robot], bounds: (i32, i32)) -> String {
+        let (_, robots) = part1_robot_sim(robots, bounds);
+
+        let grid = get_robot_grid(&robots, bounds);
+        grid_string(grid)
+    }
+
+    fn grid_string(grid: Vec<Vec<usize>>) -> String {
+        let mut ret = String::new();
+        for row in &grid {
+            for &cell in row {
+                if cell > 0 {
+                    ret.push_str(&cell.to_string());
+                } else {
+                    ret.push('.');
+                }
+            }
+            ret.push('\n');
+        }
+        ret
+    }
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(
+            part1_bounds(&get_robots(TEST_INPUT).unwrap(), (7, 11)),
+            "12"
+        );
+    }
+
+    #[test]
+    fn test_part1_grid() {
+        assert_eq!(
+            part1_map(&get_robots(TEST_INPUT).unwrap(), (7, 11)),
+            "......2..1.
+...........
+1..........
+.11........
+.....1.....
+...12......
+.1....1....\n"
+        );
+    }
+
+    // five robots that all meet in the middle of an 11x11 room after 3 steps, and are spread
+    // out at every other step since their velocities differ
+    static CONVERGING: &str = "p=2,5 v=1,0
+p=5,2 v=0,1
+p=2,2 v=1,1
+p=10,7 v=2,3
+p=8,10 v=-1,2";
+
+    #[test]
+    fn test_part2() {
+        let robots = get_robots(CONVERGING).unwrap();
+        assert_eq!(part2_bounds(&robots, (11, 11), 11), 3);
+        // stopping before they meet finds somewhere else
+        assert_ne!(part2_bounds(&robots, (11, 11), 3), 3);
+    }
+
+    #[test]
+    fn test_grid_variance() {
+        let mut robots = get_robots(CONVERGING).unwrap();
+        for robot in &mut robots {
+            robot.simulate(3, (11, 11));
+        }
+        assert_eq!(grid_variance(&get_robot_grid(&robots, (11, 11))), 0.0);
+        assert!(grid_variance(&get_robot_grid(&get_robots(CONVERGING).unwrap(), (11, 11))) > 0.0);
+    }
+}
@@ -0,0 +1,361 @@
+use std::fmt;
+
+use super::{widen, World};
+use crate::generate::Rng;
+use crate::*;
+
+/**
+ * Something that should never happen to a warehouse.
+ */
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Violation {
+    RobotOnWall((usize, usize)),
+    RobotInBox {
+        pos: (usize, usize),
+        id: usize,
+    },
+    BoxOnWall {
+        id: usize,
+        cell: (usize, usize),
+    },
+    /**
+     * The cell-to-box lookup disagrees with the boxes' own cells, e.g. two boxes overlapping.
+     */
+    Overlap {
+        cell: (usize, usize),
+    },
+    BoxCount {
+        before: usize,
+        after: usize,
+    },
+    /**
+     * A box isn't the same shape it started as.
+     */
+    Bent {
+        id: usize,
+    },
+    /**
+     * A `[` without a `]` right after it, or the other way round.
+     */
+    UnpairedHalf((usize, usize)),
+    RobotCount(usize),
+}
+
+impl fmt::Display for Violation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Violation::RobotOnWall(pos) => write!(f, "robot is inside the wall at {:?}", pos),
+            Violation::RobotInBox { pos, id } => {
+                write!(f, "robot at {:?} is inside box {}", pos, id)
+            }
+            Violation::BoxOnWall { id, cell } => {
+                write!(f, "box {} overlaps the wall at {:?}", id, cell)
+            }
+            Violation::Overlap { cell } => write!(f, "boxes overlap at {:?}", cell),
+            Violation::BoxCount { before, after } => {
+                write!(f, "there were {} boxes, now there are {}", before, after)
+            }
+            Violation::Bent { id } => write!(f, "box {} changed shape", id),
+            Violation::UnpairedHalf(pos) => write!(f, "box half at {:?} has no partner", pos),
+            Violation::RobotCount(n) => write!(f, "drawing has {} robots", n),
+        }
+    }
+}
+
+impl std::error::Error for Violation {}
+
+/**
+ * Cell offsets from the top-left corner, which stay the same however a box moves.
+ */
+fn shape(cells: &[(usize, usize)], top_left: (usize, usize)) -> Vec<(usize, usize)> {
+    let mut shape: Vec<_> = cells
+        .iter()
+        .map(|&(row, col)| (row - top_left.0, col - top_left.1))
+        .collect();
+    shape.sort();
+    shape
+}
+
+/**
+ * Checks everything that should hold after any number of moves from `start`.
+ */
+pub fn check(world: &World, start: &World) -> Result<(), Violation> {
+    world.check_consistency()?;
+
+    let (before, after) = (start.objects(), world.objects());
+    if before.len() != after.len() {
+        return Err(Violation::BoxCount {
+            before: before.len(),
+            after: after.len(),
+        });
+    }
+    for (id, (old, new)) in before.iter().zip(after).enumerate() {
+        if shape(&old.cells, old.top_left()) != shape(&new.cells, new.top_left())
+            || old.glyphs != new.glyphs
+        {
+            return Err(Violation::Bent { id });
+        }
+    }
+    check_drawing(&world.to_string())
+}
+
+/**
+ * Checks a drawn warehouse: exactly one `@`, and every `[` directly followed by a `]`.
+ */
+pub fn check_drawing(drawing: &str) -> Result<(), Violation> {
+    let robots = drawing.chars().filter(|&c| c == '@').count();
+    if robots != 1 {
+        return Err(Violation::RobotCount(robots));
+    }
+    for (row, line) in drawing.lines().enumerate() {
+        let line: Vec<char> = line.chars().collect();
+        for (col, &c) in line.iter().enumerate() {
+            let paired = match c {
+                '[' => line.get(col + 1) == Some(&']'),
+                ']' => col > 0 && line[col - 1] == '[',
+                _ => true,
+            };
+            if !paired {
+                return Err(Violation::UnpairedHalf((row, col)));
+            }
+        }
+    }
+    Ok(())
+}
+
+/**
+ * Makes every move, checking the invariants after each one. On failure, says which move (1 being
+ * the first) broke what.
+ */
+pub fn run_checked(world: &World, directions: &[Direction]) -> Result<World, (usize, Violation)> {
+    let mut current = world.clone();
+    check(&current, world).map_err(|v| (0, v))?;
+    for (i, &direction) in directions.iter().enumerate() {
+        current.push(direction);
+        check(&current, world).map_err(|v| (i + 1, v))?;
+    }
+    Ok(current)
+}
+
+/**
+ * The obvious char-grid version of the puzzle, straight from the rules: `O` and `@` push whatever
+ * is in front of them, and a `[]` moving up or down pushes whatever is in front of both halves.
+ */
+pub struct Naive {
+    grid: Vec<Vec<char>>,
+    robot: (usize, usize),
+}
+
+impl Naive {
+    pub fn new(layout: &str) -> Self {
+        let grid = parse_string_array(layout);
+        let robot = Grid::from_rows(grid.clone()).find(&'@').unwrap();
+        Naive { grid, robot }
+    }
+
+    fn next(pos: (usize, usize), direction: Direction) -> (usize, usize) {
+        direction.step(pos).unwrap()
+    }
+
+    /**
+     * The other half of a wide box, if there is one at `pos`.
+     */
+    fn partner(&self, pos: (usize, usize)) -> Option<(usize, usize)> {
+        match self.grid[pos.0][pos.1] {
+            '[' => Some((pos.0, pos.1 + 1)),
+            ']' => Some((pos.0, pos.1 - 1)),
+            _ => None,
+        }
+    }
+
+    fn can_move(&self, pos: (usize, usize), direction: Direction) -> bool {
+        match self.grid[pos.0][pos.1] {
+            '.' => true,
+            '#' => false,
+            _ => match self.partner(pos) {
+                Some(other) if direction.is_vertical() => {
+                    self.can_move(Self::next(pos, direction), direction)
+                        && self.can_move(Self::next(other, direction), direction)
+                }
+                _ => self.can_move(Self::next(pos, direction), direction),
+            },
+        }
+    }
+
+    fn do_move(&mut self, pos: (usize, usize), direction: Direction) {
+        let c = self.grid[pos.0][pos.1];
+        if c == '.' {
+            return;
+        }
+        let mut cells = vec![pos];
+        if direction.is_vertical() {
+            cells.extend(self.partner(pos));
+        }
+        for &cell in &cells {
+            self.do_move(Self::next(cell, direction), direction);
+        }
+        for &cell in &cells {
+            let next = Self::next(cell, direction);
+            self.grid[next.0][next.1] = self.grid[cell.0][cell.1];
+            self.grid[cell.0][cell.1] = '.';
+        }
+    }
+
+    pub fn push(&mut self, direction: Direction) {
+        if self.can_move(self.robot, direction) {
+            self.do_move(self.robot, direction);
+            self.robot = Self::next(self.robot, direction);
+        }
+    }
+
+    pub fn gps(&self) -> usize {
+        Grid::from_rows(self.grid.clone())
+            .iter()
+            .filter(|(_, &c)| c == 'O' || c == '[')
+            .map(|((row, col), _)| row * 100 + col)
+            .sum()
+    }
+}
+
+impl fmt::Display for Naive {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let rows: Vec<String> = self.grid.iter().map(|row| row.iter().collect()).collect();
+        write!(f, "{}", rows.join("\n"))
+    }
+}
+
+/**
+ * Random walled warehouses with random moves, each run through `World` (checking invariants
+ * after every move) and `Naive` side by side. Fails on the first disagreement, with enough to
+ * reproduce it.
+ */
+pub fn fuzz(seed: u64, cases: usize, wide: bool) -> Result<(), String> {
+    let mut rng = Rng::new(seed);
+
+    for case in 0..cases {
+        let (width, height) = (4 + rng.below(10), 4 + rng.below(8));
+        let mut rows: Vec<Vec<char>> = (0..height)
+            .map(|row| {
+                (0..width)
+                    .map(|col| {
+                        if row == 0 || col == 0 || row == height - 1 || col == width - 1 {
+                            return '#';
+                        }
+                        match rng.below(10) {
+                            0 => '#',
+                            1..=3 => 'O',
+                            _ => '.',
+                        }
+                    })
+                    .collect()
+            })
+            .collect();
+        rows[1 + rng.below(height - 2)][1 + rng.below(width - 2)] = '@';
+        let mut layout: String = rows
+            .iter()
+            .map(|row| row.iter().collect::<String>())
+            .collect::<Vec<_>>()
+            .join("\n");
+        if wide {
+            layout = widen(&layout);
+        }
+        let moves: Vec<Direction> = (0..rng.below(200))
+            .map(|_| Direction::from_index(rng.below(4)))
+            .collect();
+
+        let report = |step: usize, problem: String| {
+            let arrows: String = moves.iter().map(|d| d.arrow()).collect();
+            format!(
+                "case {} (seed {}), move {}: {}\n{}\n\n{}",
+                case, seed, step, problem, layout, arrows
+            )
+        };
+
+        let start = World::parse(&layout);
+        let mut world = start.clone();
+        let mut naive = Naive::new(&layout);
+        for (i, &direction) in moves.iter().enumerate() {
+            world.push(direction);
+            naive.push(direction);
+            if let Err(v) = check(&world, &start) {
+                return Err(report(i + 1, v.to_string()));
+            }
+            if world.to_string() != naive.to_string() {
+                return Err(report(
+                    i + 1,
+                    format!("engine\n{}\nreference\n{}", world, naive),
+                ));
+            }
+        }
+        if world.gps() != naive.gps() {
+            return Err(report(moves.len(), "GPS sums differ".to_string()));
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fuzz() {
+        fuzz(15, 300, false).unwrap();
+        fuzz(1515, 300, true).unwrap();
+    }
+
+    #[test]
+    fn test_violations() {
+        assert_eq!(
+            check_drawing("#[]@#\n#.]..#"),
+            Err(Violation::UnpairedHalf((1, 2)))
+        );
+        assert_eq!(check_drawing("#[]@@"), Err(Violation::RobotCount(2)));
+
+        let start = World::parse("#####\n#@O.#\n#####");
+        let mut moved = start.clone();
+        moved.push(Direction::Right);
+        assert_eq!(check(&moved, &start), Ok(()));
+        let other = World::parse("#####\n#@OO#\n#####");
+        assert_eq!(
+            check(&other, &start),
+            Err(Violation::BoxCount {
+                before: 1,
+                after: 2
+            })
+        );
+        let bent = World::parse("#####\n#@a.#\n#.a.#\n#####");
+        let straight = World::parse("#####\n#@aa#\n#...#\n#####");
+        assert_eq!(check(&bent, &straight), Err(Violation::Bent { id: 0 }));
+    }
+
+    #[test]
+    fn test_naive() {
+        // the lower box catches the upper one by its right half
+        let mut naive = Naive::new(
+            "\
+########
+#......#
+#..[]..#
+#...[].#
+#...@..#
+########",
+        );
+        naive.push(Direction::Up);
+        assert_eq!(
+            naive.to_string(),
+            "\
+########
+#..[]..#
+#...[].#
+#...@..#
+#......#
+########"
+        );
+        assert_eq!(naive.gps(), 103 + 204);
+        // and now the upper one is against the wall
+        let before = naive.to_string();
+        naive.push(Direction::Up);
+        assert_eq!(naive.to_string(), before);
+    }
+}
@@ -0,0 +1,237 @@
+use super::World;
+use crate::*;
+
+/**
+ * What one step did. `pushed` lists the boxes that moved along with the robot; a blocked step
+ * moves nothing but is still a step.
+ */
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Move {
+    pub direction: Direction,
+    pub robot_from: (usize, usize),
+    pub moved: bool,
+    pub pushed: Vec<usize>,
+}
+
+/**
+ * A world plus the log of moves that led to it, so steps can be undone, redone, jumped between
+ * and bisected.
+ */
+#[derive(Clone, Debug)]
+pub struct History {
+    world: World,
+    moves: Vec<Move>,
+    // how many of `moves` are applied to `world`; the rest can be redone
+    position: usize,
+}
+
+impl History {
+    pub fn new(world: World) -> Self {
+        History {
+            world,
+            moves: Vec::new(),
+            position: 0,
+        }
+    }
+
+    pub fn world(&self) -> &World {
+        &self.world
+    }
+
+    /**
+     * Number of moves applied so far.
+     */
+    pub fn position(&self) -> usize {
+        self.position
+    }
+
+    /**
+     * Number of moves recorded, including undone ones.
+     */
+    pub fn len(&self) -> usize {
+        self.moves.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.moves.is_empty()
+    }
+
+    pub fn moves(&self) -> &[Move] {
+        &self.moves
+    }
+
+    /**
+     * The move that led to the current state.
+     */
+    pub fn last_move(&self) -> Option<&Move> {
+        self.position.checked_sub(1).map(|i| &self.moves[i])
+    }
+
+    /**
+     * Makes a move, dropping anything that was undone (like typing after an undo).
+     */
+    pub fn step(&mut self, direction: Direction) -> &Move {
+        self.moves.truncate(self.position);
+        let robot_from = self.world.robot();
+        let pushed = self.world.push(direction);
+        self.moves.push(Move {
+            direction,
+            robot_from,
+            moved: pushed.is_some(),
+            pushed: pushed.unwrap_or_default(),
+        });
+        self.position += 1;
+        &self.moves[self.position - 1]
+    }
+
+    /**
+     * Makes every move in a string of arrows, skipping anything else (like newlines).
+     */
+    pub fn replay(&mut self, arrows: &str) {
+        for direction in arrows.chars().filter_map(Direction::from_arrow) {
+            self.step(direction);
+        }
+    }
+
+    pub fn undo(&mut self) -> bool {
+        let Some(last) = self.last_move() else {
+            return false;
+        };
+        if last.moved {
+            let (direction, pushed) = (last.direction, last.pushed.clone());
+            self.world.unpush(direction, &pushed);
+        }
+        self.position -= 1;
+        true
+    }
+
+    pub fn redo(&mut self) -> bool {
+        let Some(next) = self.moves.get(self.position) else {
+            return false;
+        };
+        if next.moved {
+            let direction = next.direction;
+            let pushed = self.world.push(direction);
+            debug_assert_eq!(pushed.as_ref(), Some(&next.pushed));
+        }
+        self.position += 1;
+        true
+    }
+
+    /**
+     * Undoes or redoes until `step` moves are applied. Stops at the ends of the log, returning
+     * where it ended up.
+     */
+    pub fn jump(&mut self, step: usize) -> usize {
+        while self.position > step && self.undo() {}
+        while self.position < step && self.redo() {}
+        self.position
+    }
+
+    /**
+     * Finds the first step (0 being the start) whose state `diverged` rejects, assuming every
+     * state after it is rejected too, and jumps there. None, back at the end, if even the last
+     * state is fine.
+     */
+    pub fn bisect(&mut self, mut diverged: impl FnMut(usize, &World) -> bool) -> Option<usize> {
+        let end = self.len();
+        self.jump(end);
+        if !diverged(end, &self.world) {
+            return None;
+        }
+        // diverged at hi, not at lo - 1
+        let (mut lo, mut hi) = (0, end);
+        while lo < hi {
+            let mid = (lo + hi) / 2;
+            self.jump(mid);
+            if diverged(mid, &self.world) {
+                hi = mid;
+            } else {
+                lo = mid + 1;
+            }
+        }
+        self.jump(hi);
+        Some(hi)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    static LAYOUT: &str = "\
+########
+#..O.O.#
+##@.O..#
+#...O..#
+#.#.O..#
+#...O..#
+#......#
+########";
+
+    static MOVES: &str = "<^^>>>vv<v>>v<<";
+
+    #[test]
+    fn test_undo_redo() {
+        let start = World::parse(LAYOUT);
+        let mut history = History::new(start.clone());
+        history.replay(MOVES);
+        let end = history.world().clone();
+        assert_eq!(
+            end.to_string(),
+            "\
+########
+#....OO#
+##.....#
+#.....O#
+#.#O@..#
+#...O..#
+#...O..#
+########"
+        );
+        // the first move runs into a wall
+        assert!(!history.moves()[0].moved);
+        // the fifth pushes two boxes right
+        assert_eq!(history.moves()[4].pushed.len(), 2);
+
+        assert_eq!(history.jump(0), 0);
+        assert_eq!(history.world(), &start);
+        assert!(!history.undo());
+        assert_eq!(history.jump(100), MOVES.len());
+        assert_eq!(history.world(), &end);
+        assert!(!history.redo());
+
+        // a new move after undoing throws away the undone ones
+        history.jump(5);
+        history.step(Direction::Down);
+        assert_eq!(history.len(), 6);
+        assert!(!history.redo());
+        assert!(history.undo());
+        assert!(history.redo());
+        assert_eq!(history.last_move().unwrap().direction, Direction::Down);
+    }
+
+    #[test]
+    fn test_bisect() {
+        // the expected run agrees with ours for the first 6 moves, then goes up instead of down
+        let diverging = "<^^>>>^^<v>>v<<";
+        let mut expected = History::new(World::parse(LAYOUT));
+        expected.replay(diverging);
+        let states: Vec<String> = (0..=diverging.len())
+            .map(|i| {
+                expected.jump(i);
+                expected.world().to_string()
+            })
+            .collect();
+
+        let mut history = History::new(World::parse(LAYOUT));
+        history.replay(MOVES);
+        let found = history.bisect(|step, world| world.to_string() != states[step]);
+        assert_eq!(found, Some(7));
+        assert_eq!(history.position(), 7);
+        assert_eq!(history.last_move().unwrap().direction, Direction::Down);
+
+        assert_eq!(history.bisect(|_, _| false), None);
+        assert_eq!(history.position(), MOVES.len());
+    }
+}
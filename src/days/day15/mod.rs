@@ -0,0 +1,185 @@
+use crate::error::Error;
+use crate::solution::{Solution, Variant};
+use crate::*;
+
+pub mod check;
+pub mod history;
+pub mod player;
+mod world;
+
+pub use world::{Object, World};
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    type Input = (String, Vec<Direction>);
+
+    fn day(&self) -> i32 {
+        15
+    }
+
+    fn title(&self) -> &'static str {
+        "Warehouse Woes"
+    }
+
+    fn parse(&self, input: &str) -> Result<Self::Input, Error> {
+        Ok(parse(input))
+    }
+
+    fn part1(&self, input: &Self::Input) -> String {
+        part1(input)
+    }
+
+    fn part2(&self, input: &Self::Input) -> String {
+        part2(input)
+    }
+
+    fn variants(&self) -> Vec<Variant<Self::Input>> {
+        vec![
+            Variant::new("visual", part1_visual),
+            Variant::new("checked", part2_checked),
+        ]
+    }
+}
+
+fn part1(input: &(String, Vec<Direction>)) -> String {
+    let (layout, directions) = input;
+    let mut warehouse = warehouse(layout);
+    for &direction in directions {
+        warehouse.push(direction);
+    }
+
+    warehouse.gps().to_string()
+}
+
+fn part1_visual(input: &(String, Vec<Direction>)) -> String {
+    let (layout, directions) = input;
+    let mut warehouse = warehouse(layout);
+    for &direction in directions {
+        warehouse.push(direction);
+        println!("{}", direction.arrow());
+        println!("{}\n", warehouse);
+    }
+
+    warehouse.gps().to_string()
+}
+
+fn part2(input: &(String, Vec<Direction>)) -> String {
+    let (layout, directions) = input;
+    let mut warehouse = widehouse(layout);
+    for &direction in directions {
+        warehouse.push(direction);
+    }
+
+    warehouse.gps().to_string()
+}
+
+/**
+ * Part 2, checking every invariant after every move.
+ */
+fn part2_checked(input: &(String, Vec<Direction>)) -> String {
+    let (layout, directions) = input;
+    match check::run_checked(&widehouse(layout), directions) {
+        Ok(warehouse) => warehouse.gps().to_string(),
+        Err((step, violation)) => format!("move {}: {}", step, violation),
+    }
+}
+
+// returns warehouse layout, array of indices to direction array
+fn parse(input: &str) -> (String, Vec<Direction>) {
+    let sections = split_sections(input);
+    let directions = parse_directions(sections[1]);
+    (sections[0].to_string(), directions)
+}
+
+fn parse_directions(directions: &str) -> Vec<Direction> {
+    directions
+        .chars()
+        .filter_map(Direction::from_arrow)
+        .collect()
+}
+
+/**
+ * Part 1's warehouse: the layout as given, every box a single `O`.
+ */
+pub fn warehouse(layout: &str) -> World {
+    World::parse(layout)
+}
+
+/**
+ * Part 2's warehouse: everything twice as wide, so every box is a `[]`.
+ */
+pub fn widehouse(layout: &str) -> World {
+    World::parse(&widen(layout))
+}
+
+fn widen(layout: &str) -> String {
+    let rows: Vec<String> = layout
+        .lines()
+        .map(|row| {
+            row.chars()
+                .map(|c| match c {
+                    '@' => "@.",
+                    '#' => "##",
+                    'O' => "[]",
+                    _ => "..",
+                })
+                .collect()
+        })
+        .collect();
+    rows.join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part1_small() {
+        assert_eq!(part1(&parse(TEST_INPUT_1)), "2028");
+    }
+
+    #[test]
+    fn test_part1_big() {
+        assert_eq!(part1(&parse(TEST_INPUT_2)), "10092");
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(part2(&parse(TEST_INPUT_2)), "9021");
+        assert_eq!(part2_checked(&parse(TEST_INPUT_2)), "9021");
+    }
+
+    static TEST_INPUT_1: &str = "########
+#..O.O.#
+##@.O..#
+#...O..#
+#.#.O..#
+#...O..#
+#......#
+########
+
+<^^>>>vv<v>>v<<";
+
+    static TEST_INPUT_2: &str = "##########
+#..O..O.O#
+#......O.#
+#.OO..O.O#
+#..O@..O.#
+#O#..O...#
+#O..O..O.#
+#.OO.O.OO#
+#....O...#
+##########
+
+<vv>^<v^>v>^vv^v>v<>v^v<v<^vv<<<^><<><>>v<vvv<>^v^>^<<<><<v<<<v^vv^v>^
+vvv<<^>^v^^><<>>><>^<<><^vv^^<>vvv<>><^^v>^>vv<>v<<<<v<^v>^<^^>>>^<v<v
+><>vv>v^v^<>><>>>><^^>vv>v<^^^>>v^v^<^^>v^^>v^<^v>v<>>v^v^<v>v^^<^^vv<
+<<v<^>>^^^^>>>v^<>vvv^><v<<<>^^^vv^<vvv>^>v<^^^^v<>^>vvvv><>>v^<<^^^^^
+^><^><>>><>^^<<^^v>>><^<v>^<vv>>v>>>^v><>^v><<<<v>>v<v<v>vvv>^<><<>^><
+^>><>^v<><^vvv<^^<><v<<<<<><^v<<<><<<^^<v<^^^><^>>^<v^><<<^>>^v<v^v<v^
+>^>>^v>vv>^<<^v<>><<><<v<<v><>v<^vv<<<>^^v^>^^>>><<^v>>v^v><^^>>^<>vv^
+<><^^>^^^<><vvvvv^v<v<<>^v<v>v<<^><<><<><<<^^<<<^<<>><<><^^^>^^<>^>v<>
+^^>vv<^v^v<vv>^<><v<^v>^^^>>>^^vvv^>vvv<>>>^<^>>>>>^<<^v>^vvv<>^<><<v>
+v^^>>><<^^<>>^v^<v^vv<>v^<<>^<^v^v><^<<<><<^<v><v<>vv>>v><v^<vv<>v^<<^";
+}
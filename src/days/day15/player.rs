@@ -0,0 +1,228 @@
+use super::history::History;
+use super::World;
+use crate::*;
+
+const CLEAR: &str = "\x1b[2J\x1b[H";
+const RESET: &str = "\x1b[0m";
+const WALL: &str = "\x1b[90m";
+const ROBOT: &str = "\x1b[1;33m";
+const BOX: &str = "\x1b[36m";
+const MOVED: &str = "\x1b[1;30;42m";
+
+/**
+ * Milliseconds between steps while playing, slowest first.
+ */
+const DELAYS: [u64; 9] = [1000, 500, 250, 100, 50, 20, 10, 5, 1];
+
+pub const KEYS: &str = "[space] play/pause  [n] step  [b] back  [+/-] speed  [r] restart  [q] quit";
+
+/**
+ * Draws the warehouse with colours, picking out the boxes in `moved`. Without `ansi` it's the
+ * same as `World`'s Display, except moved boxes are drawn with `*`.
+ */
+pub fn render(world: &World, moved: &[usize], ansi: bool) -> String {
+    let mut cells: Vec<Vec<(char, &str)>> = (0..world.height())
+        .map(|row| {
+            (0..world.width())
+                .map(|col| match world.is_wall((row, col)) {
+                    true => ('#', WALL),
+                    false => ('.', ""),
+                })
+                .collect()
+        })
+        .collect();
+    for (id, object) in world.objects().iter().enumerate() {
+        let is_moved = moved.contains(&id);
+        for (&(row, col), &glyph) in object.cells.iter().zip(&object.glyphs) {
+            cells[row][col] = match (is_moved, ansi) {
+                (true, true) => (glyph, MOVED),
+                (true, false) => ('*', ""),
+                (false, _) => (glyph, BOX),
+            };
+        }
+    }
+    let (row, col) = world.robot();
+    cells[row][col] = ('@', ROBOT);
+
+    let lines: Vec<String> = cells
+        .iter()
+        .map(|line| {
+            let mut out = String::new();
+            for &(c, colour) in line {
+                if ansi && !colour.is_empty() {
+                    out += colour;
+                    out.push(c);
+                    out += RESET;
+                } else {
+                    out.push(c);
+                }
+            }
+            out
+        })
+        .collect();
+    lines.join("\n")
+}
+
+/**
+ * Playback state for stepping through a run of moves.
+ */
+pub struct Player {
+    history: History,
+    playing: bool,
+    speed: usize,
+}
+
+impl Player {
+    /**
+     * Records every move up front, then rewinds to the start.
+     */
+    pub fn new(world: World, directions: &[Direction]) -> Self {
+        let mut history = History::new(world);
+        for &direction in directions {
+            history.step(direction);
+        }
+        history.jump(0);
+        Player {
+            history,
+            playing: false,
+            speed: 3,
+        }
+    }
+
+    pub fn world(&self) -> &World {
+        self.history.world()
+    }
+
+    pub fn is_playing(&self) -> bool {
+        self.playing
+    }
+
+    pub fn finished(&self) -> bool {
+        self.history.position() == self.history.len()
+    }
+
+    /**
+     * How long to wait before the next `tick`.
+     */
+    pub fn delay_ms(&self) -> u64 {
+        DELAYS[self.speed]
+    }
+
+    /**
+     * Handles a key press. Returns false to quit, on `q` or Ctrl-C.
+     */
+    pub fn key(&mut self, key: char) -> bool {
+        match key {
+            ' ' | 'p' => self.playing = !self.playing && !self.finished(),
+            'n' | '.' => {
+                self.playing = false;
+                self.history.redo();
+            }
+            'b' | ',' => {
+                self.playing = false;
+                self.history.undo();
+            }
+            '+' | '=' => self.speed = (self.speed + 1).min(DELAYS.len() - 1),
+            '-' | '_' => self.speed = self.speed.saturating_sub(1),
+            'r' => {
+                self.playing = false;
+                self.history.jump(0);
+            }
+            'q' | '\x03' => return false,
+            _ => {}
+        }
+        true
+    }
+
+    /**
+     * Advances one move if playing, and pauses at the end.
+     */
+    pub fn tick(&mut self) {
+        if self.playing && !self.history.redo() {
+            self.playing = false;
+        }
+        if self.finished() {
+            self.playing = false;
+        }
+    }
+
+    /**
+     * The whole screen: the warehouse, then a status line and the keys.
+     */
+    pub fn frame(&self, ansi: bool) -> String {
+        let moved: &[usize] = self.history.last_move().map_or(&[], |m| &m.pushed);
+        let last = match self.history.last_move() {
+            Some(m) if !m.moved => format!("{} (blocked)", m.direction.arrow()),
+            Some(m) => format!("{} (pushed {})", m.direction.arrow(), m.pushed.len()),
+            None => "start".to_string(),
+        };
+        let status = format!(
+            "step {}/{}  {}  gps {}  {}  {}ms/step",
+            self.history.position(),
+            self.history.len(),
+            last,
+            self.world().gps(),
+            if self.playing { "playing" } else { "paused" },
+            self.delay_ms()
+        );
+        format!(
+            "{}{}\n\n{}\n{}\n",
+            if ansi { CLEAR } else { "" },
+            render(self.world(), moved, ansi),
+            status,
+            KEYS
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    static LAYOUT: &str = "\
+#####
+#@O.#
+#####";
+
+    #[test]
+    fn test_render() {
+        let mut world = World::parse(LAYOUT);
+        assert_eq!(render(&world, &[], false), LAYOUT);
+        let moved = world.push(Direction::Right).unwrap();
+        assert_eq!(render(&world, &moved, false), "#####\n#.@*#\n#####");
+        let ansi = render(&world, &moved, true);
+        assert!(ansi.contains(&format!("{}O{}", MOVED, RESET)));
+        assert!(ansi.contains(&format!("{}@{}", ROBOT, RESET)));
+    }
+
+    #[test]
+    fn test_player() {
+        let moves = [Direction::Right, Direction::Right, Direction::Left];
+        let mut player = Player::new(World::parse(LAYOUT), &moves);
+        assert!(player.frame(false).contains("step 0/3  start"));
+
+        assert!(player.key('n'));
+        assert!(player.frame(false).contains("step 1/3  > (pushed 1)"));
+        assert!(player.frame(false).starts_with("#####\n#.@*#"));
+        player.key('n');
+        assert!(player.frame(false).contains("> (blocked)"));
+        player.key('b');
+        player.key('b');
+        assert_eq!(player.world(), &World::parse(LAYOUT));
+
+        let delay = player.delay_ms();
+        player.key('+');
+        assert!(player.delay_ms() < delay);
+        player.key(' ');
+        assert!(player.is_playing());
+        for _ in 0..5 {
+            player.tick();
+        }
+        assert!(player.finished());
+        assert!(!player.is_playing());
+        player.key('r');
+        assert_eq!(player.world(), &World::parse(LAYOUT));
+        assert!(!player.key('q'));
+        assert!(!player.key('\x03'));
+    }
+}
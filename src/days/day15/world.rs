@@ -0,0 +1,436 @@
+use std::{
+    collections::{HashSet, VecDeque},
+    fmt,
+};
+
+use super::check::Violation;
+use crate::*;
+
+/**
+ * A rigid box covering any set of cells. `glyphs[i]` is how `cells[i]` is drawn.
+ */
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Object {
+    pub cells: Vec<(usize, usize)>,
+    pub glyphs: Vec<char>,
+}
+
+impl Object {
+    /**
+     * Top-left corner of the object's bounding box, which is what its GPS coordinate measures.
+     */
+    pub fn top_left(&self) -> (usize, usize) {
+        let row = self.cells.iter().map(|c| c.0).min().unwrap();
+        let col = self.cells.iter().map(|c| c.1).min().unwrap();
+        (row, col)
+    }
+}
+
+/**
+ * Push physics for a robot among walls and boxes of any shape. Pushing a box pushes every box in
+ * the way of any of its cells, and the whole lot only moves if none of them hit a wall.
+ */
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct World {
+    walls: Grid<bool>,
+    // which object covers each cell
+    owner: Grid<Option<usize>>,
+    objects: Vec<Object>,
+    robot: (usize, usize),
+}
+
+impl World {
+    pub fn new(walls: Grid<bool>, robot: (usize, usize)) -> Self {
+        let owner = Grid::new(walls.width(), walls.height(), None);
+        World {
+            walls,
+            owner,
+            objects: Vec::new(),
+            robot,
+        }
+    }
+
+    /**
+     * Reads a map. `#` is a wall and `@` the robot. Boxes can be
+     * - `O`, one cell;
+     * - `[]`, two cells side by side;
+     * - any other letter, where touching cells with the same letter make up one box.
+     *
+     * Anything else is floor. Panics if there's no robot.
+     */
+    pub fn parse(layout: &str) -> Self {
+        let chars = Grid::parse(layout);
+        let robot = chars.find(&'@').expect("no robot in warehouse");
+        let mut world = World::new(chars.map(|&c| c == '#'), robot);
+        let mut taken = chars.map(|_| false);
+
+        for pos in chars.positions() {
+            if taken[pos] {
+                continue;
+            }
+            let c = chars[pos];
+            let cells = match c {
+                'O' => vec![pos],
+                '[' => {
+                    let right = (pos.0, pos.1 + 1);
+                    if chars.get(right) != Some(&']') {
+                        panic!("`[` at {:?} has no `]` after it", pos);
+                    }
+                    vec![pos, right]
+                }
+                ']' => panic!("`]` at {:?} has no `[` before it", pos),
+                c if c.is_ascii_alphabetic() => {
+                    // flood fill the letter
+                    let mut cells = vec![pos];
+                    let mut seen = HashSet::from([pos]);
+                    let mut i = 0;
+                    while i < cells.len() {
+                        for next in chars.neighbours4(cells[i]) {
+                            if chars[next] == c && seen.insert(next) {
+                                cells.push(next);
+                            }
+                        }
+                        i += 1;
+                    }
+                    cells
+                }
+                _ => continue,
+            };
+            for &cell in &cells {
+                taken[cell] = true;
+            }
+            let glyphs = cells.iter().map(|&cell| chars[cell]).collect();
+            world.add_object(cells, glyphs);
+        }
+        world
+    }
+
+    /**
+     * Adds a box and returns its id. Panics if it overlaps a wall or another box.
+     */
+    pub fn add_object(&mut self, cells: Vec<(usize, usize)>, glyphs: Vec<char>) -> usize {
+        assert_eq!(cells.len(), glyphs.len(), "one glyph per cell");
+        assert!(!cells.is_empty(), "boxes need at least one cell");
+        let id = self.objects.len();
+        for &cell in &cells {
+            assert!(
+                !self.walls[cell] && self.owner[cell].is_none(),
+                "{:?} is already taken",
+                cell
+            );
+            self.owner[cell] = Some(id);
+        }
+        self.objects.push(Object { cells, glyphs });
+        id
+    }
+
+    pub fn robot(&self) -> (usize, usize) {
+        self.robot
+    }
+
+    pub fn width(&self) -> usize {
+        self.walls.width()
+    }
+
+    pub fn height(&self) -> usize {
+        self.walls.height()
+    }
+
+    pub fn objects(&self) -> &[Object] {
+        &self.objects
+    }
+
+    pub fn is_wall(&self, pos: (usize, usize)) -> bool {
+        self.walls[pos]
+    }
+
+    /**
+     * Id of the box covering `pos`, if any.
+     */
+    pub fn object_at(&self, pos: (usize, usize)) -> Option<usize> {
+        self.owner[pos]
+    }
+
+    /**
+     * Finds every box the robot would push, breadth first through whatever each box runs into.
+     * None if something hits a wall (or the edge of the map).
+     */
+    fn pushed_by_robot(&self, direction: Direction) -> Option<Vec<usize>> {
+        let first = self.walls.offset(self.robot, direction)?;
+        if self.walls[first] {
+            return None;
+        }
+        let mut pushed = Vec::new();
+        let mut seen = HashSet::new();
+        let mut queue = VecDeque::new();
+        if let Some(id) = self.owner[first] {
+            seen.insert(id);
+            queue.push_back(id);
+        }
+        while let Some(id) = queue.pop_front() {
+            pushed.push(id);
+            for &cell in &self.objects[id].cells {
+                let next = self.walls.offset(cell, direction)?;
+                if self.walls[next] {
+                    return None;
+                }
+                if let Some(other) = self.owner[next] {
+                    if seen.insert(other) {
+                        queue.push_back(other);
+                    }
+                }
+            }
+        }
+        Some(pushed)
+    }
+
+    /**
+     * Moves the robot one step, pushing whatever is in the way. Returns the ids of the boxes that
+     * moved, or None (changing nothing) if the robot is stuck.
+     */
+    pub fn push(&mut self, direction: Direction) -> Option<Vec<usize>> {
+        let pushed = self.pushed_by_robot(direction)?;
+        self.shift(&pushed, direction);
+        Some(pushed)
+    }
+
+    /**
+     * Takes back a `push` that returned `pushed`: the robot and those boxes step back the other
+     * way.
+     */
+    pub fn unpush(&mut self, direction: Direction, pushed: &[usize]) {
+        self.shift(pushed, direction.reverse());
+    }
+
+    /**
+     * Moves the robot and the given boxes a step without checking anything.
+     */
+    fn shift(&mut self, ids: &[usize], direction: Direction) {
+        for &id in ids {
+            for &cell in &self.objects[id].cells {
+                self.owner[cell] = None;
+            }
+        }
+        for &id in ids {
+            for cell in self.objects[id].cells.iter_mut() {
+                *cell = direction.step(*cell).unwrap();
+                self.owner[*cell] = Some(id);
+            }
+        }
+        self.robot = direction.step(self.robot).unwrap();
+    }
+
+    /**
+     * Checks the robot and boxes are only on floor, and that the cell-to-box lookup matches the
+     * boxes' own cells, so no two boxes overlap.
+     */
+    pub fn check_consistency(&self) -> Result<(), Violation> {
+        if !self.walls.in_bounds(self.robot) {
+            return Err(Violation::RobotCount(0));
+        }
+        if self.walls[self.robot] {
+            return Err(Violation::RobotOnWall(self.robot));
+        }
+        if let Some(id) = self.owner[self.robot] {
+            return Err(Violation::RobotInBox {
+                pos: self.robot,
+                id,
+            });
+        }
+        let mut covered = 0;
+        for (id, object) in self.objects.iter().enumerate() {
+            for &cell in &object.cells {
+                if self.walls[cell] {
+                    return Err(Violation::BoxOnWall { id, cell });
+                }
+                if self.owner[cell] != Some(id) {
+                    return Err(Violation::Overlap { cell });
+                }
+            }
+            covered += object.cells.len();
+        }
+        // any other owned cell is a stale entry left behind by a box that moved
+        match self
+            .owner
+            .iter()
+            .filter(|(_, owner)| owner.is_some())
+            .nth(covered)
+        {
+            Some((cell, _)) => Err(Violation::Overlap { cell }),
+            None => Ok(()),
+        }
+    }
+
+    /**
+     * Sum of 100 * row + column of every box's top-left corner.
+     */
+    pub fn gps(&self) -> usize {
+        self.objects
+            .iter()
+            .map(|object| {
+                let (row, col) = object.top_left();
+                row * 100 + col
+            })
+            .sum()
+    }
+}
+
+impl fmt::Display for World {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut chars = self.walls.map(|&wall| if wall { '#' } else { '.' });
+        for object in &self.objects {
+            for (&cell, &glyph) in object.cells.iter().zip(&object.glyphs) {
+                chars[cell] = glyph;
+            }
+        }
+        chars[self.robot] = '@';
+        write!(f, "{}", chars)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn push_all(world: &mut World, moves: &str) {
+        for c in moves.chars() {
+            world.push(Direction::from_arrow(c).unwrap());
+        }
+    }
+
+    #[test]
+    fn test_parse_and_display() {
+        let layout = "\
+#######
+#.O[].#
+#.aab.#
+#..ab@#
+#######";
+        let world = World::parse(layout);
+        assert_eq!(world.objects().len(), 4);
+        assert_eq!(world.object_at((2, 2)), world.object_at((3, 3)));
+        assert_ne!(world.object_at((2, 3)), world.object_at((2, 4)));
+        assert_eq!(world.robot(), (3, 5));
+        assert_eq!(world.to_string(), layout);
+        // O at (1, 2), [] at (1, 3), L-shaped a from (2, 2), b from (2, 4)
+        assert_eq!(world.gps(), 102 + 103 + 202 + 204);
+    }
+
+    #[test]
+    fn test_wide_and_tall() {
+        // a 3-wide box pushed up catches both boxes above it
+        let mut world = World::parse(
+            "\
+#######
+#.....#
+#.[]x.#
+#.yyy.#
+#..@..#
+#######",
+        );
+        assert_eq!(world.push(Direction::Up).map(|p| p.len()), Some(3));
+        assert_eq!(
+            world.to_string(),
+            "\
+#######
+#.[]x.#
+#.yyy.#
+#..@..#
+#.....#
+#######"
+        );
+        // now the [] box is against the wall, so nothing moves
+        assert_eq!(world.push(Direction::Up), None);
+
+        // a tall box only pushes what's in front of it
+        let mut world = World::parse(
+            "\
+######
+#.t..#
+#.tO@#
+#.t..#
+######",
+        );
+        push_all(&mut world, "<");
+        assert_eq!(world.to_string().lines().nth(2), Some("#tO@.#"));
+        assert_eq!(world.object_at((1, 1)), world.object_at((3, 1)));
+        assert_eq!(world.push(Direction::Left), None);
+        assert_eq!(world.robot(), (2, 3));
+    }
+
+    #[test]
+    fn test_l_shape() {
+        // the L's foot catches the O
+        let mut world = World::parse(
+            "\
+#######
+#.....#
+#..O..#
+#.LL..#
+#.L...#
+#.L@..#
+#######",
+        );
+        push_all(&mut world, "^^");
+        assert_eq!(
+            world.to_string(),
+            "\
+#######
+#..O..#
+#.LL..#
+#.L@..#
+#.L...#
+#.....#
+#######"
+        );
+        assert_eq!(world.push(Direction::Up), None, "the O is against the wall");
+        // pushing the L sideways drags all of it
+        push_all(&mut world, ">v<<");
+        assert_eq!(world.robot(), (4, 2));
+        assert_eq!(
+            world.objects()[world.object_at((4, 1)).unwrap()].top_left(),
+            (2, 1)
+        );
+    }
+
+    #[test]
+    fn test_check_consistency() {
+        let mut world = World::parse("######\n#@O[]#\n######");
+        assert_eq!(world.check_consistency(), Ok(()));
+        world.owner[(1, 3)] = Some(0);
+        assert_eq!(
+            world.check_consistency(),
+            Err(Violation::Overlap { cell: (1, 3) })
+        );
+        world.owner[(1, 3)] = Some(1);
+        world.owner[(1, 1)] = Some(1);
+        assert_eq!(
+            world.check_consistency(),
+            Err(Violation::RobotInBox { pos: (1, 1), id: 1 })
+        );
+        world.owner[(1, 1)] = None;
+        world.objects[0].cells[0] = (0, 2);
+        assert_eq!(
+            world.check_consistency(),
+            Err(Violation::BoxOnWall {
+                id: 0,
+                cell: (0, 2)
+            })
+        );
+    }
+
+    #[test]
+    fn test_no_walls() {
+        // without a border the robot just can't step off the map
+        let mut world = World::parse("@O");
+        assert_eq!(world.push(Direction::Left), None);
+        assert_eq!(world.push(Direction::Right), None);
+        assert_eq!(world.push(Direction::Down), None);
+    }
+
+    #[test]
+    #[should_panic(expected = "no robot in warehouse")]
+    fn test_no_robot() {
+        World::parse("#####\n#.O.#\n#####");
+    }
+}
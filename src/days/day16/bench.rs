@@ -0,0 +1,214 @@
+use std::{
+    collections::HashSet,
+    fmt,
+    time::{Duration, Instant},
+};
+
+use super::Maze;
+use crate::*;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Method {
+    Dijkstra,
+    AStar,
+    Bidirectional,
+}
+
+impl Method {
+    pub const ALL: [Method; 3] = [Method::Dijkstra, Method::AStar, Method::Bidirectional];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Method::Dijkstra => "dijkstra",
+            Method::AStar => "a*",
+            Method::Bidirectional => "bidirectional",
+        }
+    }
+}
+
+/**
+ * How one search did on one maze: both answers, how many states it settled and how long it took.
+ */
+#[derive(Clone, Debug)]
+pub struct Measurement {
+    pub method: Method,
+    pub cost: Option<usize>,
+    pub seats: usize,
+    pub expanded: usize,
+    pub elapsed: Duration,
+}
+
+impl fmt::Display for Measurement {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let cost = self.cost.map_or("-".to_string(), |c| c.to_string());
+        write!(
+            f,
+            "{:<14} {:>10} {:>7} {:>10} {:>12.2?}",
+            self.method.name(),
+            cost,
+            self.seats,
+            self.expanded,
+            self.elapsed
+        )
+    }
+}
+
+pub fn measure(maze: &Maze, method: Method) -> Measurement {
+    let timer = Instant::now();
+    let (cost, states, expanded) = match method {
+        Method::Dijkstra | Method::AStar => {
+            let paths = match method {
+                Method::Dijkstra => maze.search(),
+                _ => maze.astar(),
+            };
+            let states = paths.on_optimal_paths(paths.goals());
+            (paths.goal_distance(), states, paths.settled().len())
+        }
+        Method::Bidirectional => {
+            let search = maze.bidirectional();
+            (
+                search.distance(),
+                search.on_optimal_paths(),
+                search.expanded(),
+            )
+        }
+    };
+    let seats: HashSet<(usize, usize)> = states.into_iter().map(|(i, j, _)| (i, j)).collect();
+    Measurement {
+        method,
+        cost,
+        seats: seats.len(),
+        expanded,
+        elapsed: timer.elapsed(),
+    }
+}
+
+/**
+ * Runs every method on `maze`, as a table headed with `label`.
+ */
+pub fn report(label: &str, maze: &Maze) -> String {
+    let grid = maze.grid();
+    let mut out = format!(
+        "{} ({}x{})\n{:<14} {:>10} {:>7} {:>10} {:>12}\n",
+        label,
+        grid.width(),
+        grid.height(),
+        "method",
+        "cost",
+        "seats",
+        "expanded",
+        "time"
+    );
+    for method in Method::ALL {
+        out += &format!("{}\n", measure(maze, method));
+    }
+    out
+}
+
+/**
+ * `n` by `n` copies of a walled maze sharing their outer walls, with a door through every tenth
+ * place a shared wall has floor on both sides. Only the bottom-left copy's `S` and the top-right
+ * copy's `E` are kept.
+ */
+pub fn tiled(grid: &Grid<char>, n: usize) -> Grid<char> {
+    // copies overlap by their outer walls, which are the same all round
+    let (h, w) = (grid.height() - 1, grid.width() - 1);
+    let mut big = Grid::new(w * n + 1, h * n + 1, '#');
+    for block_row in 0..n {
+        for block_col in 0..n {
+            for ((i, j), &c) in grid.iter() {
+                let pos = (block_row * h + i, block_col * w + j);
+                big[pos] = match c {
+                    'S' if block_row != n - 1 || block_col != 0 => '.',
+                    'E' if block_row != 0 || block_col != n - 1 => '.',
+                    c => c,
+                };
+            }
+        }
+    }
+
+    let open = |c: char| c != '#';
+    for k in 1..n {
+        // the walls between block rows and between block columns
+        let (row, col) = (k * h, k * w);
+        let across: Vec<(usize, usize)> = (1..big.width() - 1)
+            .map(|j| (row, j))
+            .filter(|&(i, j)| open(big[(i - 1, j)]) && open(big[(i + 1, j)]))
+            .collect();
+        let down: Vec<(usize, usize)> = (1..big.height() - 1)
+            .map(|i| (i, col))
+            .filter(|&(i, j)| open(big[(i, j - 1)]) && open(big[(i, j + 1)]))
+            .collect();
+        for door in across
+            .into_iter()
+            .step_by(10)
+            .chain(down.into_iter().step_by(10))
+        {
+            big[door] = '.';
+        }
+    }
+    big
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::parse;
+    use super::*;
+    use crate::generate::{reindeer_maze, MazeOptions};
+
+    static MAZE: &str = "\
+#######
+#...#E#
+#.#...#
+#S..#.#
+#######";
+
+    #[test]
+    fn test_methods_agree() {
+        let maze = parse(MAZE).unwrap();
+        let results: Vec<Measurement> = Method::ALL.iter().map(|&m| measure(&maze, m)).collect();
+        for result in &results {
+            assert_eq!(result.cost, results[0].cost);
+            assert_eq!(result.seats, results[0].seats);
+            assert!(result.expanded > 0);
+        }
+        let table = report("small", &maze);
+        assert_eq!(table.lines().count(), 5);
+        assert!(table.starts_with("small (7x5)"));
+    }
+
+    #[test]
+    fn test_tiled() {
+        let big = tiled(&Grid::parse(MAZE), 3);
+        assert_eq!((big.width(), big.height()), (19, 13));
+        assert_eq!(big.find_all(&'S').collect::<Vec<_>>(), vec![(11, 1)]);
+        assert_eq!(big.find_all(&'E').collect::<Vec<_>>(), vec![(1, 17)]);
+        let maze = Maze::new(big).unwrap();
+        let results: Vec<Measurement> = Method::ALL.iter().map(|&m| measure(&maze, m)).collect();
+        assert!(results[0].cost.is_some(), "the copies are joined up");
+        for result in &results {
+            assert_eq!(result.cost, results[0].cost);
+            assert_eq!(result.seats, results[0].seats);
+        }
+    }
+
+    #[test]
+    fn test_generated_mazes() {
+        for (seed, loopiness, dead_ends) in [(1, 0.0, 1.0), (2, 0.05, 0.5), (3, 0.3, 0.0)] {
+            let options = MazeOptions {
+                width: 61,
+                height: 41,
+                loopiness,
+                dead_ends,
+            };
+            let maze = parse(&reindeer_maze(seed, &options)).unwrap();
+            let results: Vec<Measurement> =
+                Method::ALL.iter().map(|&m| measure(&maze, m)).collect();
+            assert!(results[0].cost.is_some());
+            for result in &results {
+                assert_eq!(result.cost, results[0].cost, "seed {}", seed);
+                assert_eq!(result.seats, results[0].seats, "seed {}", seed);
+            }
+        }
+    }
+}
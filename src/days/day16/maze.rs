@@ -0,0 +1,521 @@
+use std::fmt;
+
+use super::State;
+use crate::pathfind::{astar, bidirectional, dijkstra, dijkstra_to, Bidirectional, ShortestPaths};
+use crate::*;
+
+/**
+ * What each kind of move costs the reindeer. Moves that step onto a digit cost that many times
+ * as much, so `3` is a tile that's three times as slow to cross (and `0` is free, which is fine
+ * for costs but muddles path counts).
+ */
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Costs {
+    pub forward: usize,
+    pub turn_left: usize,
+    pub turn_right: usize,
+    /**
+     * Turning all the way round on the spot, if that's allowed at all. Two quarter turns are
+     * always possible anyway.
+     */
+    pub u_turn: Option<usize>,
+    /**
+     * Stepping backwards without turning round, if that's allowed.
+     */
+    pub backward: Option<usize>,
+}
+
+impl Costs {
+    /**
+     * The puzzle's rules: forward 1, quarter turns 1000, nothing else.
+     */
+    pub const PUZZLE: Costs = Costs {
+        forward: 1,
+        turn_left: 1000,
+        turn_right: 1000,
+        u_turn: None,
+        backward: None,
+    };
+
+    /**
+     * Reads `forward,left,right[,u-turn[,backward]]`, where `-` leaves a move out, e.g.
+     * `1,1000,1000,-,5`.
+     */
+    pub fn parse(s: &str) -> Option<Costs> {
+        let parts: Vec<Option<usize>> = s
+            .split(',')
+            .map(|part| match part.trim() {
+                "-" => Some(None),
+                n => n.parse().ok().map(Some),
+            })
+            .collect::<Option<_>>()?;
+        match parts.as_slice() {
+            [Some(forward), Some(turn_left), Some(turn_right), rest @ ..] if rest.len() <= 2 => {
+                Some(Costs {
+                    forward: *forward,
+                    turn_left: *turn_left,
+                    turn_right: *turn_right,
+                    u_turn: rest.first().copied().flatten(),
+                    backward: rest.get(1).copied().flatten(),
+                })
+            }
+            _ => None,
+        }
+    }
+}
+
+impl Default for Costs {
+    fn default() -> Self {
+        Costs::PUZZLE
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MazeError {
+    Missing(char),
+    /**
+     * More than one `S` (or `E`), at the two positions given.
+     */
+    Repeated {
+        tile: char,
+        first: (usize, usize),
+        second: (usize, usize),
+    },
+}
+
+impl fmt::Display for MazeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MazeError::Missing(tile) => write!(f, "the maze has no `{}`", tile),
+            MazeError::Repeated {
+                tile,
+                first,
+                second,
+            } => write!(
+                f,
+                "the maze has more than one `{}`: {:?} and {:?}",
+                tile, first, second
+            ),
+        }
+    }
+}
+
+impl std::error::Error for MazeError {}
+
+/**
+ * A reindeer maze: the map, where `S` and `E` are, which way the reindeer starts facing and what
+ * moving costs.
+ */
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Maze {
+    grid: Grid<char>,
+    start: (usize, usize),
+    end: (usize, usize),
+    facing: Direction,
+    costs: Costs,
+}
+
+impl Maze {
+    /**
+     * Finds `S` and `E`, and sets up the puzzle's rules: facing east, with `Costs::PUZZLE`.
+     */
+    pub fn new(grid: Grid<char>) -> Result<Self, MazeError> {
+        let find = |tile: char| {
+            let mut found = grid.find_all(&tile);
+            let first = found.next().ok_or(MazeError::Missing(tile))?;
+            match found.next() {
+                Some(second) => Err(MazeError::Repeated {
+                    tile,
+                    first,
+                    second,
+                }),
+                None => Ok(first),
+            }
+        };
+        let (start, end) = (find('S')?, find('E')?);
+        Ok(Maze {
+            grid,
+            start,
+            end,
+            facing: Direction::Right,
+            costs: Costs::PUZZLE,
+        })
+    }
+
+    pub fn with_costs(mut self, costs: Costs) -> Self {
+        self.costs = costs;
+        self
+    }
+
+    pub fn facing(mut self, facing: Direction) -> Self {
+        self.facing = facing;
+        self
+    }
+
+    pub fn grid(&self) -> &Grid<char> {
+        &self.grid
+    }
+
+    pub fn start(&self) -> (usize, usize) {
+        self.start
+    }
+
+    pub fn end(&self) -> (usize, usize) {
+        self.end
+    }
+
+    pub fn costs(&self) -> &Costs {
+        &self.costs
+    }
+
+    pub fn start_state(&self) -> State {
+        (self.start.0, self.start.1, self.facing)
+    }
+
+    pub fn is_end(&self, &(i, j, _): &State) -> bool {
+        (i, j) == self.end
+    }
+
+    /**
+     * How much more than usual it costs to step onto `pos`: the digit there, or 1. None for walls
+     * and anything off the map.
+     */
+    pub fn weight(&self, pos: (usize, usize)) -> Option<usize> {
+        match self.grid.get(pos)? {
+            '#' => None,
+            c => Some(c.to_digit(10).map_or(1, |d| d as usize)),
+        }
+    }
+
+    /**
+     * Every move from `state`, with its cost.
+     */
+    pub fn moves(&self, (i, j, facing): State) -> Vec<(State, usize)> {
+        let costs = &self.costs;
+        let mut next = vec![
+            ((i, j, facing.turn_left()), costs.turn_left),
+            ((i, j, facing.turn_right()), costs.turn_right),
+        ];
+        if let Some(cost) = costs.u_turn {
+            next.push(((i, j, facing.reverse()), cost));
+        }
+        let steps = [
+            (facing, Some(costs.forward)),
+            (facing.reverse(), costs.backward),
+        ];
+        for (direction, cost) in steps {
+            let Some(cost) = cost else {
+                continue;
+            };
+            let Some(to) = self.grid.offset((i, j), direction) else {
+                continue;
+            };
+            if let Some(weight) = self.weight(to) {
+                next.push(((to.0, to.1, facing), cost * weight));
+            }
+        }
+        next
+    }
+
+    /**
+     * `moves` backwards: every state that can move to `state`, with the move's cost.
+     */
+    pub fn moves_into(&self, (i, j, facing): State) -> Vec<(State, usize)> {
+        let Some(weight) = self.weight((i, j)) else {
+            return Vec::new();
+        };
+        let costs = &self.costs;
+        let mut prev = vec![
+            ((i, j, facing.turn_right()), costs.turn_left),
+            ((i, j, facing.turn_left()), costs.turn_right),
+        ];
+        if let Some(cost) = costs.u_turn {
+            prev.push(((i, j, facing.reverse()), cost));
+        }
+        // forward moves come from behind, backward ones from in front
+        let steps = [
+            (facing.reverse(), Some(costs.forward)),
+            (facing, costs.backward),
+        ];
+        for (direction, cost) in steps {
+            let Some(cost) = cost else {
+                continue;
+            };
+            let Some(from) = self.grid.offset((i, j), direction) else {
+                continue;
+            };
+            if self.weight(from).is_some() {
+                prev.push(((from.0, from.1, facing), cost * weight));
+            }
+        }
+        prev
+    }
+
+    /**
+     * A lower bound on the cost from any state to the end: the Manhattan distance at the cheapest
+     * step, plus a turn for each direction the reindeer still has to go in but can't move in
+     * facing the way it is. Consistent, so A* with it finds every cheapest path.
+     */
+    pub fn heuristic(&self) -> impl Fn(&State) -> usize + '_ {
+        let costs = &self.costs;
+        let lightest = self
+            .grid
+            .positions()
+            .filter_map(|pos| self.weight(pos))
+            .min()
+            .unwrap_or(1);
+        let step = costs.forward.min(costs.backward.unwrap_or(usize::MAX)) * lightest;
+        let u_turn = costs.u_turn.unwrap_or(usize::MAX);
+        let quarter = costs.turn_left.min(costs.turn_right);
+        let turn = quarter.min(u_turn);
+        let about_face = quarter.saturating_mul(2).min(u_turn);
+
+        move |&(i, j, facing)| {
+            let (ei, ej) = self.end;
+            let vertical = match i.cmp(&ei) {
+                std::cmp::Ordering::Less => Some(Direction::Down),
+                std::cmp::Ordering::Greater => Some(Direction::Up),
+                std::cmp::Ordering::Equal => None,
+            };
+            let horizontal = match j.cmp(&ej) {
+                std::cmp::Ordering::Less => Some(Direction::Right),
+                std::cmp::Ordering::Greater => Some(Direction::Left),
+                std::cmp::Ordering::Equal => None,
+            };
+            let can_go =
+                |d: Direction| d == facing || (costs.backward.is_some() && d == facing.reverse());
+            let missing: Vec<Direction> = [vertical, horizontal]
+                .into_iter()
+                .flatten()
+                .filter(|&d| !can_go(d))
+                .collect();
+            let turns = match missing.as_slice() {
+                [] => 0,
+                [d] if *d == facing.reverse() => about_face,
+                [_] => turn,
+                _ => turn.saturating_mul(2),
+            };
+            (i.abs_diff(ei) + j.abs_diff(ej)) * step + turns
+        }
+    }
+
+    /**
+     * Dijkstra from the start until the end is reached, in whichever orientation(s) are cheapest.
+     */
+    pub fn search(&self) -> ShortestPaths<State> {
+        dijkstra_to(
+            [self.start_state()],
+            |&state| self.moves(state),
+            |state| self.is_end(state),
+        )
+    }
+
+    /**
+     * `search`, but A* with `heuristic`.
+     */
+    pub fn astar(&self) -> ShortestPaths<State> {
+        astar(
+            [self.start_state()],
+            |&state| self.moves(state),
+            self.heuristic(),
+            |state| self.is_end(state),
+        )
+    }
+
+    /**
+     * `search`, but from both ends at once: out from the start and back from every way of
+     * standing on the end.
+     */
+    pub fn bidirectional(&self) -> Bidirectional<State> {
+        let (i, j) = self.end;
+        bidirectional(
+            [self.start_state()],
+            Direction::ALL.map(|facing| (i, j, facing)),
+            |&state| self.moves(state),
+            |&state| self.moves_into(state),
+        )
+    }
+
+    /**
+     * Dijkstra from the start over the whole maze, for when the distances to tiles past the end
+     * matter too.
+     */
+    pub fn survey(&self) -> ShortestPaths<State> {
+        dijkstra([self.start_state()], |&state| self.moves(state))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn maze(layout: &str) -> Maze {
+        Maze::new(Grid::parse(layout)).unwrap()
+    }
+
+    fn best(maze: &Maze) -> Option<usize> {
+        maze.search().goal_distance()
+    }
+
+    #[test]
+    fn test_find_start_end() {
+        let m = maze("#####\n#E.S#\n#####");
+        assert_eq!((m.start(), m.end()), ((1, 3), (1, 1)));
+        // facing east, it has to turn round first
+        assert_eq!(best(&m), Some(2002));
+        assert_eq!(best(&m.clone().facing(Direction::Left)), Some(2));
+
+        assert_eq!(Maze::new(Grid::parse("#S.#")), Err(MazeError::Missing('E')));
+        assert_eq!(
+            Maze::new(Grid::parse("SES")),
+            Err(MazeError::Repeated {
+                tile: 'S',
+                first: (0, 0),
+                second: (0, 2)
+            })
+        );
+    }
+
+    #[test]
+    fn test_costs() {
+        let m = maze("#####\n#E.S#\n#####");
+        let u_turn = Costs {
+            u_turn: Some(1500),
+            ..Costs::PUZZLE
+        };
+        assert_eq!(best(&m.clone().with_costs(u_turn)), Some(1502));
+        let backward = Costs {
+            backward: Some(10),
+            ..Costs::PUZZLE
+        };
+        assert_eq!(best(&m.clone().with_costs(backward)), Some(20));
+        // lopsided turns: three rights beat one left
+        let m = maze("###\n#E#\n#S#\n###");
+        let lopsided = Costs {
+            turn_left: 5000,
+            ..Costs::PUZZLE
+        };
+        assert_eq!(best(&m.clone().with_costs(lopsided)), Some(3001));
+        assert_eq!(
+            best(&m.with_costs(lopsided).facing(Direction::Down)),
+            Some(2001)
+        );
+    }
+
+    #[test]
+    fn test_weights() {
+        // straight through the 9 or round the 1s
+        let m = maze(
+            "\
+#####
+#S9E#
+#.11#
+#####",
+        );
+        assert_eq!(m.weight((1, 2)), Some(9));
+        assert_eq!(m.weight((0, 0)), None);
+        assert_eq!(best(&m), Some(10));
+        let cheap_turns = Costs {
+            turn_left: 1,
+            turn_right: 1,
+            ..Costs::PUZZLE
+        };
+        assert_eq!(best(&m.with_costs(cheap_turns)), Some(7));
+    }
+
+    #[test]
+    fn test_moves_into() {
+        let m = maze("#####\n#S1E#\n#.9.#\n#####").with_costs(Costs {
+            u_turn: Some(7),
+            backward: Some(3),
+            ..Costs::PUZZLE
+        });
+        for pos in m.grid().positions().filter(|&pos| m.weight(pos).is_some()) {
+            for facing in Direction::ALL {
+                let state = (pos.0, pos.1, facing);
+                for (next, cost) in m.moves(state) {
+                    assert!(m.moves_into(next).contains(&(state, cost)));
+                }
+                for (prev, cost) in m.moves_into(state) {
+                    assert!(m.moves(prev).contains(&(state, cost)));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_searches_agree() {
+        let layouts = [
+            "#####\n#E.S#\n#####",
+            "#####\n#S9E#\n#.11#\n#####",
+            "#######\n#...#E#\n#.#...#\n#S..#.#\n#######",
+        ];
+        let rules = [
+            Costs::PUZZLE,
+            Costs {
+                turn_left: 5000,
+                u_turn: Some(1500),
+                ..Costs::PUZZLE
+            },
+            Costs {
+                backward: Some(10),
+                ..Costs::PUZZLE
+            },
+        ];
+        for layout in layouts {
+            for costs in rules {
+                for facing in Direction::ALL {
+                    let m = maze(layout).with_costs(costs).facing(facing);
+                    let plain = m.search();
+                    let seats = plain.on_optimal_paths(plain.goals());
+                    let guided = m.astar();
+                    assert_eq!(guided.goal_distance(), plain.goal_distance());
+                    assert_eq!(guided.on_optimal_paths(guided.goals()), seats);
+                    let both = m.bidirectional();
+                    assert_eq!(both.distance(), plain.goal_distance());
+                    assert_eq!(both.on_optimal_paths(), seats);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_heuristic_is_consistent() {
+        let m = maze("#######\n#...#E#\n#.#...#\n#S..#.#\n#######");
+        for costs in [
+            Costs::PUZZLE,
+            Costs {
+                turn_right: 1,
+                u_turn: Some(3),
+                backward: Some(2),
+                ..Costs::PUZZLE
+            },
+        ] {
+            let m = m.clone().with_costs(costs);
+            let h = m.heuristic();
+            for pos in m.grid().positions() {
+                for facing in Direction::ALL {
+                    let state = (pos.0, pos.1, facing);
+                    for (next, cost) in m.moves(state) {
+                        assert!(h(&state) <= cost + h(&next), "{:?} -> {:?}", state, next);
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_parse_costs() {
+        assert_eq!(Costs::parse("1,1000,1000"), Some(Costs::PUZZLE));
+        assert_eq!(
+            Costs::parse("1, 1000, 1000, -, 5"),
+            Some(Costs {
+                backward: Some(5),
+                ..Costs::PUZZLE
+            })
+        );
+        assert_eq!(Costs::parse("1,1000"), None);
+        assert_eq!(Costs::parse("1,1000,1000,1,2,3"), None);
+        assert_eq!(Costs::parse("1,x,1000"), None);
+    }
+}
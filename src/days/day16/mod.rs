@@ -0,0 +1,227 @@
+use std::collections::HashSet;
+
+use crate::error::Error;
+use crate::solution::{Solution, Variant};
+use crate::*;
+
+pub mod bench;
+mod maze;
+pub mod render;
+pub mod routes;
+
+pub use maze::{Costs, Maze, MazeError};
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    type Input = Maze;
+
+    fn day(&self) -> i32 {
+        16
+    }
+
+    fn title(&self) -> &'static str {
+        "Reindeer Maze"
+    }
+
+    fn parse(&self, input: &str) -> Result<Self::Input, Error> {
+        parse(input).map_err(|e| Error::BadInput(Box::new(e)))
+    }
+
+    fn part1(&self, input: &Self::Input) -> String {
+        part1(input)
+    }
+
+    fn part2(&self, input: &Self::Input) -> String {
+        part2(input)
+    }
+
+    fn variants(&self) -> Vec<Variant<Self::Input>> {
+        vec![
+            Variant::new("planner", part2_planner),
+            Variant::new("astar", part2_astar),
+            Variant::new("bidirectional", part2_bidirectional),
+        ]
+    }
+}
+
+fn part1(maze: &Maze) -> String {
+    let paths = maze.search();
+    paths.goal_distance().unwrap_or(0).to_string()
+}
+
+fn part2(maze: &Maze) -> String {
+    let paths = maze.search();
+    // walk the optimal-predecessor DAG back from every cheapest way of ending up on E
+    let seat_set: HashSet<(usize, usize)> = paths
+        .dag()
+        .reaching(paths.goals())
+        .into_iter()
+        .map(|(i, j, _)| (i, j))
+        .collect();
+    seat_set.len().to_string()
+}
+
+/**
+ * Part 2 as the zero-slack case of the near-optimal seat count.
+ */
+fn part2_planner(maze: &Maze) -> String {
+    routes::Planner::new(maze).seats_within(0).to_string()
+}
+
+/**
+ * Part 2 with A* instead of Dijkstra.
+ */
+fn part2_astar(maze: &Maze) -> String {
+    let paths = maze.astar();
+    count_seats(paths.on_optimal_paths(paths.goals())).to_string()
+}
+
+/**
+ * Part 2 searching from both ends at once.
+ */
+fn part2_bidirectional(maze: &Maze) -> String {
+    count_seats(maze.bidirectional().on_optimal_paths()).to_string()
+}
+
+fn count_seats(states: HashSet<State>) -> usize {
+    let seats: HashSet<(usize, usize)> = states.into_iter().map(|(i, j, _)| (i, j)).collect();
+    seats.len()
+}
+
+/**
+ * A tile plus the way the reindeer is facing.
+ */
+pub type State = (usize, usize, Direction);
+
+pub fn parse(input: &str) -> Result<Maze, MazeError> {
+    Maze::new(get_grid(input))
+}
+
+fn get_grid(input: &str) -> Grid<char> {
+    Grid::parse(input)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use render::Analysis;
+
+    static TEST_INPUT_1: &str = "###############
+#.......#....E#
+#.#.###.#.###.#
+#.....#.#...#.#
+#.###.#####.#.#
+#.#.#.......#.#
+#.#.#####.###.#
+#...........#.#
+###.#.#####.#.#
+#...#.....#.#.#
+#.#.#.###.#.#.#
+#.....#...#.#.#
+#.###.#.#.#.#.#
+#S..#.....#...#
+###############";
+
+    static TEST_INPUT_2: &str = "#################
+#...#...#...#..E#
+#.#.#.#.#.#.#.#.#
+#.#.#.#...#...#.#
+#.#.#.#.###.#.#.#
+#...#.#.#.....#.#
+#.#.#.#.#.#####.#
+#.#...#.#.#.....#
+#.#.#####.#.###.#
+#.#.#.......#...#
+#.#.###.#####.###
+#.#.#...#.....#.#
+#.#.#.#####.###.#
+#.#.#.........#.#
+#.#.#.#########.#
+#S#.............#
+#################";
+
+    static TEST_INPUT_3: &str = "#####
+#..E#
+#.#.#
+#...#
+#S..#
+#####";
+
+    #[test]
+    fn test_part1_small() {
+        assert_eq!(part1(&parse(TEST_INPUT_1).unwrap()), "7036");
+    }
+
+    #[test]
+    fn test_part1_large() {
+        assert_eq!(part1(&parse(TEST_INPUT_2).unwrap()), "11048");
+    }
+
+    #[test]
+    fn test_part2_small() {
+        assert_eq!(part2(&parse(TEST_INPUT_1).unwrap()), "45");
+    }
+
+    #[test]
+    fn test_part2_large() {
+        assert_eq!(part2(&parse(TEST_INPUT_2).unwrap()), "64");
+    }
+
+    #[test]
+    fn test_count_paths() {
+        // the puzzle text draws three best paths through the first example
+        let paths = parse(TEST_INPUT_1).unwrap().search();
+        let count: usize = paths.goals().iter().map(|g| paths.count_paths(g)).sum();
+        assert_eq!(count, 3);
+    }
+
+    #[test]
+    fn test_render_agrees() {
+        for (input, seats, paths) in [(TEST_INPUT_1, 45, 3), (TEST_INPUT_2, 64, 2)] {
+            let maze = parse(input).unwrap();
+            let end = maze.end();
+            let analysis = Analysis::new(maze.grid(), &maze.survey(), end);
+            assert_eq!(analysis.seats(), seats);
+            assert_eq!(analysis.total_paths(), paths);
+            assert_eq!(analysis.paths_through(end), paths);
+        }
+    }
+
+    #[test]
+    fn test_routes_agree() {
+        for (input, best, seats, paths) in
+            [(TEST_INPUT_1, 7036, 45, 3), (TEST_INPUT_2, 11048, 64, 2)]
+        {
+            let maze = parse(input).unwrap();
+            let planner = routes::Planner::new(&maze);
+            assert_eq!(planner.seats_within(0), seats);
+            assert_eq!(planner.within(0, 100).len(), paths);
+            let k_shortest = planner.k_shortest(paths + 1);
+            assert!(k_shortest[..paths].iter().all(|r| r.cost == best));
+            assert!(k_shortest[paths].cost > best);
+        }
+    }
+
+    #[test]
+    fn test_other_searches() {
+        for input in [TEST_INPUT_1, TEST_INPUT_2, TEST_INPUT_3] {
+            let maze = parse(input).unwrap();
+            assert_eq!(part2_astar(&maze), part2(&maze));
+            assert_eq!(part2_bidirectional(&maze), part2(&maze));
+            assert_eq!(
+                maze.astar().goal_distance().unwrap().to_string(),
+                part1(&maze)
+            );
+            assert_eq!(
+                maze.bidirectional().distance().unwrap().to_string(),
+                part1(&maze)
+            );
+        }
+    }
+
+    #[test]
+    fn test_both_orientations() {
+        assert_eq!(part2(&parse(TEST_INPUT_3).unwrap()), "6");
+    }
+}
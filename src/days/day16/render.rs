@@ -0,0 +1,389 @@
+use std::collections::HashMap;
+
+use super::State;
+use crate::pathfind::ShortestPaths;
+use crate::*;
+
+const RESET: &str = "\x1b[0m";
+const WALL: &str = "\x1b[38;5;240;48;5;236m";
+const PATH: &str = "\x1b[1;97m";
+
+/**
+ * What to draw on each open tile in the text renderings.
+ */
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Layer {
+    /**
+     * Which way the best paths leave each seat, `+` where they split up.
+     */
+    Arrows,
+    /**
+     * How many best paths go through each seat: 1-9, then a-z, then `+`.
+     */
+    Counts,
+    /**
+     * Distance from the start in tenths of the furthest distance, 0-9.
+     */
+    Heat,
+}
+
+/**
+ * Everything worth drawing about a searched maze, tile by tile.
+ */
+#[derive(Clone, Debug)]
+pub struct Analysis {
+    grid: Grid<char>,
+    // cheapest way to stand on each tile, whichever way round
+    dist: Grid<Option<usize>>,
+    // best paths to the end through each tile
+    counts: Grid<usize>,
+    // directions the best paths step off each tile in
+    exits: Grid<Vec<Direction>>,
+    total: usize,
+    best: Option<usize>,
+}
+
+impl Analysis {
+    /**
+     * Needs a search that settled every state (a plain `dijkstra`), so the heatmap covers the
+     * whole maze. The best paths are the ones ending on `end` at the lowest cost.
+     */
+    pub fn new(grid: &Grid<char>, paths: &ShortestPaths<State>, end: (usize, usize)) -> Self {
+        let mut dist = grid.map(|_| None::<usize>);
+        for &(i, j, facing) in paths.settled() {
+            let d = paths.distance(&(i, j, facing)).unwrap();
+            dist[(i, j)] = Some(dist[(i, j)].map_or(d, |old: usize| old.min(d)));
+        }
+        let best = dist[end];
+        let goals: Vec<State> = Direction::ALL
+            .iter()
+            .map(|&facing| (end.0, end.1, facing))
+            .filter(|goal| best.is_some() && paths.distance(goal) == best)
+            .collect();
+
+        // paths from a start to each state, and from each state to a goal. These can get huge in
+        // open mazes, so saturate rather than overflow.
+        let mut from_start: HashMap<State, usize> = HashMap::new();
+        for state in paths.settled() {
+            let preds = paths.predecessors(state);
+            let count = match preds.is_empty() {
+                true => 1,
+                false => preds
+                    .iter()
+                    .fold(0usize, |sum, p| sum.saturating_add(from_start[p])),
+            };
+            from_start.insert(*state, count);
+        }
+        let mut to_goal: HashMap<State, usize> = goals.iter().map(|&goal| (goal, 1)).collect();
+        for state in paths.settled().iter().rev() {
+            let Some(&count) = to_goal.get(state) else {
+                continue;
+            };
+            for pred in paths.predecessors(state) {
+                let entry = to_goal.entry(*pred).or_insert(0);
+                *entry = entry.saturating_add(count);
+            }
+        }
+
+        // a best path never comes back to a tile, so count each path where it steps onto one
+        let mut counts = grid.map(|_| 0usize);
+        let mut exits = grid.map(|_| Vec::new());
+        let total = goals
+            .iter()
+            .fold(0usize, |sum, goal| sum.saturating_add(from_start[goal]));
+        for (&state, &onward) in &to_goal {
+            let (i, j, _) = state;
+            let preds = paths.predecessors(&state);
+            if preds.is_empty() {
+                counts[(i, j)] = counts[(i, j)].saturating_add(onward);
+            }
+            for &(pi, pj, facing) in preds {
+                if (pi, pj) != (i, j) {
+                    let through = from_start[&(pi, pj, facing)].saturating_mul(onward);
+                    counts[(i, j)] = counts[(i, j)].saturating_add(through);
+                    if !exits[(pi, pj)].contains(&facing) {
+                        exits[(pi, pj)].push(facing);
+                    }
+                }
+            }
+        }
+
+        Analysis {
+            grid: grid.clone(),
+            dist,
+            counts,
+            exits,
+            total,
+            best,
+        }
+    }
+
+    /**
+     * Number of distinct best paths.
+     */
+    pub fn total_paths(&self) -> usize {
+        self.total
+    }
+
+    pub fn best_cost(&self) -> Option<usize> {
+        self.best
+    }
+
+    pub fn distance(&self, pos: (usize, usize)) -> Option<usize> {
+        self.dist[pos]
+    }
+
+    /**
+     * How many best paths go through `pos`.
+     */
+    pub fn paths_through(&self, pos: (usize, usize)) -> usize {
+        self.counts[pos]
+    }
+
+    /**
+     * Tiles on at least one best path.
+     */
+    pub fn seats(&self) -> usize {
+        self.counts.iter().filter(|(_, &count)| count > 0).count()
+    }
+
+    fn max_distance(&self) -> usize {
+        self.dist.iter().filter_map(|(_, d)| *d).max().unwrap_or(0)
+    }
+
+    /**
+     * Where `pos` sits between the start (0) and the furthest reachable tile (1).
+     */
+    fn heat(&self, pos: (usize, usize)) -> Option<f64> {
+        let max = self.max_distance().max(1);
+        self.dist[pos].map(|d| d as f64 / max as f64)
+    }
+
+    fn arrow(&self, pos: (usize, usize)) -> char {
+        match self.exits[pos].as_slice() {
+            [] => self.grid[pos],
+            [only] => only.arrow(),
+            _ => '+',
+        }
+    }
+
+    fn glyph(&self, pos: (usize, usize), layer: Layer) -> char {
+        let c = self.grid[pos];
+        if c == '#' {
+            return c;
+        }
+        match layer {
+            Layer::Arrows if self.counts[pos] > 0 => match c {
+                'S' | 'E' => c,
+                _ => self.arrow(pos),
+            },
+            Layer::Counts => match self.counts[pos] {
+                0 => '.',
+                n => char::from_digit(n as u32, 36)
+                    .filter(|_| n < 36)
+                    .unwrap_or('+'),
+            },
+            Layer::Heat => match self.heat(pos) {
+                Some(t) => char::from_digit(((t * 10.0) as u32).min(9), 10).unwrap(),
+                None => '.',
+            },
+            _ => '.',
+        }
+    }
+
+    /**
+     * Plain text, one character per tile.
+     */
+    pub fn text(&self, layer: Layer) -> String {
+        let rows: Vec<String> = (0..self.grid.height())
+            .map(|i| {
+                (0..self.grid.width())
+                    .map(|j| self.glyph((i, j), layer))
+                    .collect()
+            })
+            .collect();
+        rows.join("\n")
+    }
+
+    /**
+     * The chosen layer over a heatmap background, in 256-colour ANSI.
+     */
+    pub fn ansi(&self, layer: Layer) -> String {
+        let rows: Vec<String> = (0..self.grid.height())
+            .map(|i| {
+                let mut out = String::new();
+                for j in 0..self.grid.width() {
+                    let pos = (i, j);
+                    let glyph = self.glyph(pos, layer);
+                    if glyph == '#' {
+                        out += WALL;
+                    } else if let Some(t) = self.heat(pos) {
+                        // 6x6x6 colour cube, blue near the start to red far away
+                        let red = (t * 5.0).round() as usize;
+                        out += &format!("\x1b[48;5;{}m", 16 + 36 * red + (5 - red));
+                        if self.counts[pos] > 0 {
+                            out += PATH;
+                        }
+                    }
+                    out.push(glyph);
+                    out += RESET;
+                }
+                out
+            })
+            .collect();
+        rows.join("\n")
+    }
+
+    /**
+     * Colour of a tile in the images: walls dark, floor by heat, seats brighter the more best
+     * paths go through them.
+     */
+    fn colour(&self, pos: (usize, usize)) -> [u8; 3] {
+        if self.grid[pos] == '#' {
+            return [40, 40, 48];
+        }
+        let Some(t) = self.heat(pos) else {
+            return [90, 90, 90];
+        };
+        let heat = [
+            (40.0 + 200.0 * t) as u8,
+            (60.0 + 60.0 * (1.0 - (2.0 * t - 1.0).abs())) as u8,
+            (220.0 - 180.0 * t) as u8,
+        ];
+        if self.counts[pos] == 0 {
+            return heat;
+        }
+        let share = self.counts[pos] as f64 / self.total.max(1) as f64;
+        let mix = 0.5 + 0.5 * share;
+        let gold = [255.0, 215.0, 0.0];
+        [0, 1, 2].map(|k| (heat[k] as f64 * (1.0 - mix) + gold[k] * mix) as u8)
+    }
+
+    /**
+     * A binary PPM (P6) image, `scale` pixels per tile.
+     */
+    pub fn ppm(&self, scale: usize) -> Vec<u8> {
+        let (width, height) = (self.grid.width() * scale, self.grid.height() * scale);
+        let mut out = format!("P6\n{} {}\n255\n", width, height).into_bytes();
+        for y in 0..height {
+            for x in 0..width {
+                out.extend(self.colour((y / scale, x / scale)));
+            }
+        }
+        out
+    }
+
+    /**
+     * An SVG with a square per tile, the arrows on top, and each tile's distance and path count
+     * as a tooltip.
+     */
+    pub fn svg(&self, scale: usize) -> String {
+        let (width, height) = (self.grid.width(), self.grid.height());
+        let mut out = format!(
+            "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{}\" height=\"{}\" viewBox=\"0 0 {} {}\" font-family=\"monospace\" font-size=\"0.8\" text-anchor=\"middle\" dominant-baseline=\"central\">\n",
+            width * scale,
+            height * scale,
+            width,
+            height
+        );
+        for (pos, &c) in self.grid.iter() {
+            let (i, j) = pos;
+            let [r, g, b] = self.colour(pos);
+            out += &format!(
+                "<rect x=\"{}\" y=\"{}\" width=\"1\" height=\"1\" fill=\"#{:02x}{:02x}{:02x}\">",
+                j, i, r, g, b
+            );
+            if c != '#' {
+                let dist = self.dist[pos].map_or("unreachable".to_string(), |d| d.to_string());
+                out += &format!(
+                    "<title>({}, {}) distance {}, {} best paths</title>",
+                    i, j, dist, self.counts[pos]
+                );
+            }
+            out += "</rect>\n";
+            if c != '#' && self.counts[pos] > 0 {
+                let glyph = match self.glyph(pos, Layer::Arrows) {
+                    '<' => "&lt;".to_string(),
+                    '>' => "&gt;".to_string(),
+                    glyph => glyph.to_string(),
+                };
+                out += &format!("<text x=\"{}.5\" y=\"{}.5\">{}</text>\n", j, i, glyph);
+            }
+        }
+        out += "</svg>\n";
+        out
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::parse;
+    use super::*;
+
+    // three equally good ways up: columns 1, 3 and 5
+    static MAZE: &str = "\
+#########
+#.....E##
+#.#.#.#.#
+#S......#
+#########";
+
+    fn analyse(maze: &str) -> Analysis {
+        let maze = parse(maze).unwrap();
+        Analysis::new(maze.grid(), &maze.survey(), maze.end())
+    }
+
+    #[test]
+    fn test_counts() {
+        let analysis = analyse(MAZE);
+        assert_eq!(analysis.best_cost(), Some(2007));
+        assert_eq!(analysis.total_paths(), 3);
+        assert_eq!(analysis.paths_through((3, 1)), 3);
+        assert_eq!(analysis.paths_through((1, 3)), 2);
+        assert_eq!(analysis.paths_through((3, 6)), 0);
+        assert_eq!(analysis.seats(), 14);
+        assert_eq!(
+            analysis.text(Layer::Counts),
+            "\
+#########
+#112233##
+#1#1#1#.#
+#32211..#
+#########"
+        );
+    }
+
+    #[test]
+    fn test_arrows_and_heat() {
+        let analysis = analyse(MAZE);
+        assert_eq!(
+            analysis.text(Layer::Arrows),
+            "\
+#########
+#>>>>>E##
+#^#^#^#.#
+#S>+>^..#
+#########"
+        );
+        assert_eq!(analysis.distance((3, 7)), Some(6));
+        assert_eq!(analysis.text(Layer::Heat).lines().nth(3), Some("#0000000#"));
+        assert!(analysis
+            .ansi(Layer::Arrows)
+            .contains(&format!("{}>{}", PATH, RESET)));
+    }
+
+    #[test]
+    fn test_images() {
+        let analysis = analyse(MAZE);
+        let ppm = analysis.ppm(2);
+        let header = "P6\n18 10\n255\n";
+        assert!(ppm.starts_with(header.as_bytes()));
+        assert_eq!(ppm.len(), header.len() + 18 * 10 * 3);
+
+        let svg = analysis.svg(10);
+        assert!(svg.starts_with("<svg"));
+        assert_eq!(svg.matches("<rect").count(), 45);
+        assert!(svg.contains("<text x=\"2.5\" y=\"3.5\">&gt;</text>"));
+        assert!(svg.contains("(3, 3) distance"));
+    }
+}
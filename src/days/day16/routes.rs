@@ -0,0 +1,317 @@
+use std::collections::{HashMap, HashSet};
+
+use super::{Maze, State};
+use crate::pathfind::{dijkstra, dijkstra_to, ShortestPaths};
+use crate::*;
+
+/**
+ * One way through the maze from `S` to the first time it reaches `E`.
+ */
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Route {
+    pub states: Vec<State>,
+    pub cost: usize,
+}
+
+impl Route {
+    fn new(maze: &Maze, states: Vec<State>) -> Self {
+        let cost = states
+            .windows(2)
+            .map(|pair| edge_cost(maze, pair[0], pair[1]).unwrap())
+            .sum();
+        Route { states, cost }
+    }
+
+    /**
+     * Quarter turns made along the way, a U-turn counting as two.
+     */
+    pub fn turns(&self) -> usize {
+        self.states
+            .windows(2)
+            .map(|pair| match (pair[0].2, pair[1].2) {
+                (a, b) if a == b => 0,
+                (a, b) if a.reverse() == b => 2,
+                _ => 1,
+            })
+            .sum()
+    }
+
+    /**
+     * The tiles visited, in order, without the repeats from turning on the spot.
+     */
+    pub fn tiles(&self) -> Vec<(usize, usize)> {
+        let mut tiles: Vec<(usize, usize)> = self.states.iter().map(|&(i, j, _)| (i, j)).collect();
+        tiles.dedup();
+        tiles
+    }
+}
+
+fn edge_cost(maze: &Maze, from: State, to: State) -> Option<usize> {
+    maze.moves(from)
+        .into_iter()
+        .filter(|&(next, _)| next == to)
+        .map(|(_, cost)| cost)
+        .min()
+}
+
+/**
+ * Moves that don't carry on past the end, since a route stops as soon as it gets there.
+ */
+fn onward(maze: &Maze, state: State) -> Vec<(State, usize)> {
+    match maze.is_end(&state) {
+        true => Vec::new(),
+        false => maze.moves(state),
+    }
+}
+
+/**
+ * Answers questions about routes that aren't necessarily the best, using the cheapest cost from
+ * the start to every state and from every state on to the end.
+ */
+pub struct Planner<'a> {
+    maze: &'a Maze,
+    from_start: ShortestPaths<State>,
+    to_end: ShortestPaths<State>,
+    best: Option<usize>,
+}
+
+impl<'a> Planner<'a> {
+    pub fn new(maze: &'a Maze) -> Self {
+        let from_start = dijkstra([maze.start_state()], |&state| onward(maze, state));
+
+        // run the moves backwards from every way of standing on the end
+        let mut reversed: HashMap<State, Vec<(State, usize)>> = HashMap::new();
+        for &state in from_start.settled() {
+            for (next, cost) in onward(maze, state) {
+                reversed.entry(next).or_default().push((state, cost));
+            }
+        }
+        let (i, j) = maze.end();
+        let ends: Vec<State> = Direction::ALL
+            .iter()
+            .map(|&facing| (i, j, facing))
+            .filter(|end| from_start.distance(end).is_some())
+            .collect();
+        let to_end = dijkstra(ends.iter().copied(), |state| {
+            reversed.get(state).cloned().unwrap_or_default()
+        });
+
+        let best = ends.iter().filter_map(|end| from_start.distance(end)).min();
+        Planner {
+            maze,
+            from_start,
+            to_end,
+            best,
+        }
+    }
+
+    pub fn best_cost(&self) -> Option<usize> {
+        self.best
+    }
+
+    /**
+     * Cheapest route from `from` to the end that avoids `banned` states and never takes one of
+     * the `cut` moves.
+     */
+    fn shortest_avoiding(
+        &self,
+        from: State,
+        banned: &HashSet<State>,
+        cut: &HashSet<(State, State)>,
+    ) -> Option<Vec<State>> {
+        let paths = dijkstra_to(
+            [from],
+            |&state| {
+                let mut next = onward(self.maze, state);
+                next.retain(|(to, _)| !banned.contains(to) && !cut.contains(&(state, *to)));
+                next
+            },
+            |state| self.maze.is_end(state),
+        );
+        paths.path_to(paths.goals().first()?)
+    }
+
+    /**
+     * The `k` cheapest distinct routes, cheapest first (Yen's algorithm). Routes never visit the
+     * same state twice, but can cross a tile again facing another way.
+     */
+    pub fn k_shortest(&self, k: usize) -> Vec<Route> {
+        let mut found: Vec<Route> = Vec::new();
+        let mut candidates: Vec<Route> = Vec::new();
+        let Some(first) =
+            self.shortest_avoiding(self.maze.start_state(), &HashSet::new(), &HashSet::new())
+        else {
+            return found;
+        };
+        found.push(Route::new(self.maze, first));
+
+        while found.len() < k {
+            let last = found.last().unwrap().states.clone();
+            for i in 0..last.len() - 1 {
+                let root = &last[..=i];
+                // don't find any of the routes through this root again
+                let cut: HashSet<(State, State)> = found
+                    .iter()
+                    .filter(|route| route.states.len() > i + 1 && route.states[..=i] == *root)
+                    .map(|route| (route.states[i], route.states[i + 1]))
+                    .collect();
+                let banned: HashSet<State> = root[..i].iter().copied().collect();
+                let Some(spur) = self.shortest_avoiding(last[i], &banned, &cut) else {
+                    continue;
+                };
+                let mut states = root[..i].to_vec();
+                states.extend(spur);
+                if !candidates.iter().chain(&found).any(|r| r.states == states) {
+                    candidates.push(Route::new(self.maze, states));
+                }
+            }
+            // cheapest candidate, oldest first on ties so the order is stable
+            let Some(next) = (0..candidates.len()).min_by_key(|&i| (candidates[i].cost, i)) else {
+                break;
+            };
+            found.push(candidates.remove(next));
+        }
+        found
+    }
+
+    /**
+     * Every route costing at most `slack` more than the best one, cheapest first. There can be a
+     * lot, so the search gives up after finding `limit` of them, which then aren't necessarily
+     * the cheapest `limit`.
+     */
+    pub fn within(&self, slack: usize, limit: usize) -> Vec<Route> {
+        let Some(best) = self.best else {
+            return Vec::new();
+        };
+        let mut routes = Vec::new();
+        let mut path = vec![self.maze.start_state()];
+        let mut on_path = HashSet::from([self.maze.start_state()]);
+        self.extend(best + slack, 0, &mut path, &mut on_path, &mut routes, limit);
+        routes.sort_by_key(|route| route.cost);
+        routes
+    }
+
+    /**
+     * Depth first from the end of `path`, only going where the end is still in budget.
+     */
+    fn extend(
+        &self,
+        budget: usize,
+        cost: usize,
+        path: &mut Vec<State>,
+        on_path: &mut HashSet<State>,
+        routes: &mut Vec<Route>,
+        limit: usize,
+    ) {
+        let state = *path.last().unwrap();
+        if self.maze.is_end(&state) {
+            routes.push(Route {
+                states: path.clone(),
+                cost,
+            });
+            return;
+        }
+        for (next, step) in onward(self.maze, state) {
+            if routes.len() >= limit {
+                return;
+            }
+            let Some(rest) = self.to_end.distance(&next) else {
+                continue;
+            };
+            if cost + step + rest > budget || on_path.contains(&next) {
+                continue;
+            }
+            path.push(next);
+            on_path.insert(next);
+            self.extend(budget, cost + step, path, on_path, routes, limit);
+            on_path.remove(&next);
+            path.pop();
+        }
+    }
+
+    /**
+     * Tiles the reindeer could pass through on its way to the end while spending at most `slack`
+     * more than the best route. Like part 2 at a slack of 0. The route there and the route on
+     * from there are each the cheapest, so together they might cross over themselves.
+     */
+    pub fn seats_within(&self, slack: usize) -> usize {
+        let Some(best) = self.best else {
+            return 0;
+        };
+        let seats: HashSet<(usize, usize)> = self
+            .from_start
+            .settled()
+            .iter()
+            .filter(|state| {
+                let there = self.from_start.distance(state).unwrap();
+                self.to_end
+                    .distance(state)
+                    .is_some_and(|rest| there + rest <= best + slack)
+            })
+            .map(|&(i, j, _)| (i, j))
+            .collect();
+        seats.len()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::parse;
+    use super::*;
+
+    // three equally good ways up: columns 1, 3 and 5, then the long way round through column 7
+    static MAZE: &str = "\
+#########
+#.....E.#
+#.#.#.#.#
+#S......#
+#########";
+
+    #[test]
+    fn test_k_shortest() {
+        let maze = parse(MAZE).unwrap();
+        let planner = Planner::new(&maze);
+        let routes = planner.k_shortest(5);
+        let costs: Vec<usize> = routes.iter().map(|r| r.cost).collect();
+        assert_eq!(costs, vec![2007, 2007, 2007, 2009, 4007]);
+        assert!(routes.iter().take(3).all(|r| r.turns() == 2));
+        assert_eq!(routes[3].turns(), 2);
+        assert_eq!(routes[3].tiles().last(), Some(&(1, 6)));
+        assert_eq!(routes[3].tiles()[6..], [(3, 7), (2, 7), (1, 7), (1, 6)]);
+        // every route is different
+        for (i, a) in routes.iter().enumerate() {
+            assert!(routes[i + 1..].iter().all(|b| a.states != b.states));
+        }
+    }
+
+    #[test]
+    fn test_within() {
+        let maze = parse(MAZE).unwrap();
+        let planner = Planner::new(&maze);
+        assert_eq!(planner.best_cost(), Some(2007));
+        assert_eq!(planner.within(0, 100).len(), 3);
+        assert_eq!(planner.within(2, 100).len(), 4);
+        assert_eq!(planner.within(2, 2).len(), 2);
+        assert_eq!(planner.seats_within(0), 14);
+        // plus the way round through column 7
+        assert_eq!(planner.seats_within(2), 18);
+
+        // the slack-limited routes are exactly the k shortest that fit
+        let within = planner.within(2000, 1000);
+        let k_shortest = planner.k_shortest(within.len() + 1);
+        assert!(k_shortest[within.len()].cost > 2007 + 2000);
+        for (a, b) in within.iter().zip(&k_shortest) {
+            assert_eq!(a.cost, b.cost);
+        }
+    }
+
+    #[test]
+    fn test_no_way_through() {
+        let maze = parse("#####\n#S#E#\n#####").unwrap();
+        let planner = Planner::new(&maze);
+        assert_eq!(planner.best_cost(), None);
+        assert!(planner.k_shortest(3).is_empty());
+        assert!(planner.within(10, 10).is_empty());
+        assert_eq!(planner.seats_within(10), 0);
+    }
+}
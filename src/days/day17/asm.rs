@@ -0,0 +1,388 @@
+use std::{collections::HashMap, fmt};
+
+use super::INSN_NAMES;
+
+const JNZ: usize = 3;
+const BXC: usize = 4;
+
+/**
+ * What an instruction's operand means.
+ */
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum OperandKind {
+    /**
+     * 0-3 are themselves, 4-6 are registers A-C, 7 is reserved.
+     */
+    Combo,
+    Literal,
+    /**
+     * A literal that's a program address, so it can be written as a label.
+     */
+    Target,
+    Ignored,
+}
+
+fn operand_kind(opcode: usize) -> OperandKind {
+    match opcode {
+        1 => OperandKind::Literal,
+        JNZ => OperandKind::Target,
+        BXC => OperandKind::Ignored,
+        _ => OperandKind::Combo,
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AsmError {
+    /**
+     * 1-based line of the source.
+     */
+    pub line: usize,
+    pub message: String,
+}
+
+impl fmt::Display for AsmError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "line {}: {}", self.line, self.message)
+    }
+}
+
+impl std::error::Error for AsmError {}
+
+/**
+ * Assembles mnemonics into the program encoding.
+ *
+ * One instruction per line, optionally after a `label:`. `;` and `#` start comments. Combo
+ * operands are written `0`-`3`, `A`, `B` or `C` (`7` is accepted, it just crashes when run),
+ * `jnz` takes a label or an address, and `bxc` may leave out its operand. `.word n` puts a single
+ * raw value in the program, like the dangling last value of an odd-length one.
+ */
+pub fn assemble(source: &str) -> Result<Vec<usize>, AsmError> {
+    let mut labels: HashMap<&str, usize> = HashMap::new();
+    // opcode and operand, or None for a `.word`
+    let mut insns: Vec<(usize, Option<usize>, Option<&str>)> = Vec::new();
+    let mut address = 0;
+
+    for (i, line) in source.lines().enumerate() {
+        let line_no = i + 1;
+        let err = |message: String| AsmError {
+            line: line_no,
+            message,
+        };
+        let mut code = line.split([';', '#']).next().unwrap().trim();
+
+        if let Some((label, rest)) = code.split_once(':') {
+            let label = label.trim();
+            if !is_identifier(label) {
+                return Err(err(format!("`{}` isn't a valid label", label)));
+            }
+            if labels.insert(label, address).is_some() {
+                return Err(err(format!("label `{}` is defined twice", label)));
+            }
+            code = rest.trim();
+        }
+        if code.is_empty() {
+            continue;
+        }
+
+        let mut words = code.split_whitespace();
+        let mnemonic = words.next().unwrap();
+        let operand = words.next();
+        if let Some(extra) = words.next() {
+            return Err(err(format!("unexpected `{}` after the operand", extra)));
+        }
+        if mnemonic == ".word" {
+            insns.push((line_no, None, operand));
+            address += 1;
+            continue;
+        }
+        let Some(opcode) = INSN_NAMES
+            .iter()
+            .position(|name| name.eq_ignore_ascii_case(mnemonic))
+        else {
+            return Err(err(format!("unknown instruction `{}`", mnemonic)));
+        };
+        insns.push((line_no, Some(opcode), operand));
+        address += 2;
+    }
+
+    let mut program = Vec::with_capacity(address);
+    for (line_no, opcode, operand) in insns {
+        let err = |message: String| AsmError {
+            line: line_no,
+            message,
+        };
+        let Some(opcode) = opcode else {
+            match operand.and_then(|text| text.parse::<usize>().ok()) {
+                Some(n) if n <= 7 => program.push(n),
+                _ => return Err(err("`.word` takes a number from 0 to 7".to_string())),
+            }
+            continue;
+        };
+        let name = INSN_NAMES[opcode];
+        let value = match (operand_kind(opcode), operand) {
+            (OperandKind::Ignored, None) => 0,
+            (_, None) => return Err(err(format!("`{}` needs an operand", name))),
+            (kind, Some(text)) => match (kind, parse_operand(kind, text)) {
+                (OperandKind::Combo, Some(Operand::Reg(r))) => 4 + r,
+                (OperandKind::Combo, Some(Operand::Num(n @ 4..=6))) => {
+                    let reg = (b'A' + (n - 4) as u8) as char;
+                    return Err(err(format!(
+                        "combo operand {} means register {}, write `{}` instead",
+                        n, reg, reg
+                    )));
+                }
+                (_, Some(Operand::Num(n))) if n <= 7 => n,
+                (_, Some(Operand::Num(n))) => {
+                    return Err(err(format!("operand {} doesn't fit in 3 bits", n)))
+                }
+                (OperandKind::Target, None) if is_identifier(text) => match labels.get(text) {
+                    Some(&addr) if addr <= 7 => addr,
+                    Some(&addr) => {
+                        return Err(err(format!(
+                            "label `{}` is at {}, but jnz can only reach 0-7",
+                            text, addr
+                        )))
+                    }
+                    None => return Err(err(format!("no label called `{}`", text))),
+                },
+                (OperandKind::Combo, None) => {
+                    return Err(err(format!(
+                        "`{}` takes 0-3, A, B or C, not `{}`",
+                        name, text
+                    )))
+                }
+                _ => {
+                    return Err(err(format!(
+                        "`{}` takes a number from 0 to 7, not `{}`",
+                        name, text
+                    )))
+                }
+            },
+        };
+        program.push(opcode);
+        program.push(value);
+    }
+    Ok(program)
+}
+
+enum Operand {
+    Num(usize),
+    Reg(usize),
+}
+
+/**
+ * A number, or a register name unless it's a jump target, where `a` to `c` are free to be labels.
+ */
+fn parse_operand(kind: OperandKind, text: &str) -> Option<Operand> {
+    match text {
+        _ if kind == OperandKind::Target => text.parse().ok().map(Operand::Num),
+        "A" | "a" => Some(Operand::Reg(0)),
+        "B" | "b" => Some(Operand::Reg(1)),
+        "C" | "c" => Some(Operand::Reg(2)),
+        _ => text.parse().ok().map(Operand::Num),
+    }
+}
+
+fn is_identifier(s: &str) -> bool {
+    let mut chars = s.chars();
+    chars
+        .next()
+        .is_some_and(|c| c.is_ascii_alphabetic() || c == '_')
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_')
+}
+
+/**
+ * The `Program: ...` line for an assembled program.
+ */
+pub fn program_line(program: &[usize]) -> String {
+    let values: Vec<String> = program.iter().map(|v| v.to_string()).collect();
+    format!("Program: {}", values.join(","))
+}
+
+/**
+ * One instruction as the assembler would take it, with combo operands decoded.
+ */
+pub fn describe(opcode: usize, operand: usize) -> String {
+    let name = INSN_NAMES[opcode];
+    match operand_kind(opcode) {
+        OperandKind::Combo => format!("{} {}", name, combo_name(operand)),
+        _ => format!("{} {}", name, operand),
+    }
+}
+
+fn combo_name(operand: usize) -> String {
+    match operand {
+        4 => "A".to_string(),
+        5 => "B".to_string(),
+        6 => "C".to_string(),
+        n => n.to_string(),
+    }
+}
+
+/**
+ * What an instruction does, for listing comments.
+ */
+fn effect(opcode: usize, operand: usize) -> String {
+    if operand == 7 && operand_kind(opcode) == OperandKind::Combo {
+        return "reserved combo operand, crashes".to_string();
+    }
+    let combo = combo_name(operand);
+    let shift = match operand {
+        0..=3 => format!("{}", 1 << operand),
+        _ => format!("2^{}", combo),
+    };
+    match opcode {
+        0 => format!("A = A / {}", shift),
+        1 => format!("B = B ^ {}", operand),
+        2 => format!("B = {} % 8", combo),
+        3 => format!("if A != 0 goto {}", operand),
+        4 => "B = B ^ C".to_string(),
+        5 => format!("out {} % 8", combo),
+        6 => format!("B = A / {}", shift),
+        _ => format!("C = A / {}", shift),
+    }
+}
+
+/**
+ * Renders a program as a listing that `assemble` turns back into the same program. Jump targets
+ * that land on an instruction get labels, and every line has a comment saying what it does.
+ */
+pub fn disassemble(program: &[usize]) -> String {
+    let insn_count = program.len() / 2;
+    let mut targets: Vec<usize> = (0..insn_count)
+        .filter(|&i| program[2 * i] == JNZ)
+        .map(|i| program[2 * i + 1])
+        .filter(|&t| t % 2 == 0 && t / 2 < insn_count)
+        .collect();
+    targets.sort();
+    targets.dedup();
+
+    let mut lines = Vec::new();
+    for i in 0..insn_count {
+        let pc = 2 * i;
+        let (opcode, operand) = (program[pc], program[pc + 1]);
+        if targets.contains(&pc) {
+            lines.push(format!("L{}:", pc));
+        }
+        let text = if opcode == JNZ && targets.contains(&operand) {
+            format!("jnz L{}", operand)
+        } else {
+            describe(opcode, operand)
+        };
+        lines.push(format!(
+            "    {:<10}; {:2}: {}",
+            text,
+            pc,
+            effect(opcode, operand)
+        ));
+    }
+    if program.len() % 2 == 1 {
+        lines.push(format!(
+            "    {:<10}; {:2}: opcode with no operand, never runs",
+            format!(".word {}", program[program.len() - 1]),
+            program.len() - 1
+        ));
+    }
+    lines.join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::days::day17::CPU;
+
+    static SOURCE: &str = "\
+; the example from part 2
+loop:
+    adv 3      # A >>= 3
+    out A
+    jnz loop
+";
+
+    #[test]
+    fn test_assemble() {
+        assert_eq!(assemble(SOURCE), Ok(vec![0, 3, 5, 4, 3, 0]));
+        assert_eq!(
+            program_line(&assemble(SOURCE).unwrap()),
+            "Program: 0,3,5,4,3,0"
+        );
+        assert_eq!(
+            assemble("start: bxc\nBST c\njnz 2"),
+            Ok(vec![4, 0, 2, 6, 3, 2])
+        );
+        // a jump target is never a register, so registers' names are fine as labels
+        assert_eq!(
+            assemble("bst A\na: adv 1\nc: out A\njnz a\njnz c"),
+            Ok(vec![2, 4, 0, 1, 5, 4, 3, 2, 3, 4])
+        );
+    }
+
+    #[test]
+    fn test_errors() {
+        let err = |src: &str| assemble(src).unwrap_err();
+        assert_eq!(err("adv 3\nmul 2").line, 2);
+        assert!(err("out 5").message.contains("write `B`"));
+        assert!(err("bxl 8").message.contains("3 bits"));
+        assert!(err("bxl A").message.contains("not `A`"));
+        assert!(err("jnz nowhere").message.contains("no label"));
+        assert!(err("jnz A").message.contains("no label called `A`"));
+        assert!(err("x: adv 1\nx: adv 2").message.contains("twice"));
+        assert!(err("adv").message.contains("needs an operand"));
+        assert!(err(".word 9").message.contains("0 to 7"));
+        assert!(err(".word").message.contains("0 to 7"));
+        assert_eq!(
+            err("adv 1\nadv 1\nadv 1\nadv 1\nfar: adv 1\njnz far").to_string(),
+            "line 6: label `far` is at 8, but jnz can only reach 0-7"
+        );
+    }
+
+    #[test]
+    fn test_disassemble() {
+        let listing = disassemble(&[0, 1, 5, 4, 3, 0]);
+        assert_eq!(
+            listing,
+            "\
+L0:
+    adv 1     ;  0: A = A / 2
+    out A     ;  2: out A % 8
+    jnz L0    ;  4: if A != 0 goto 0"
+        );
+    }
+
+    #[test]
+    fn test_words() {
+        assert_eq!(
+            assemble(".word 2\nx: out A\njnz x"),
+            Ok(vec![2, 5, 4, 3, 1])
+        );
+        assert_eq!(
+            disassemble(&[5, 4, 6]),
+            "    out A     ;  0: out A % 8\n    .word 6   ;  2: opcode with no operand, never runs"
+        );
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let programs: [&[usize]; 6] = [
+            &[0, 1, 5, 4, 3, 0],
+            &[2, 4, 1, 1, 7, 5, 4, 0, 0, 3, 1, 6, 5, 5, 3, 0],
+            // odd jump target and a reserved combo operand still survive
+            &[3, 3, 2, 7, 4, 5, 6, 6],
+            &[],
+            // a dangling last value
+            &[0, 1, 5, 4, 3, 0, 7],
+            &[2],
+        ];
+        for program in programs {
+            assert_eq!(assemble(&disassemble(program)).unwrap(), program);
+        }
+    }
+
+    #[test]
+    fn test_round_trip_runs() {
+        let program = assemble(&disassemble(&[0, 1, 5, 4, 3, 0])).unwrap();
+        let mut cpu = CPU::new([729, 0, 0], program);
+        cpu.execute(false).unwrap();
+        assert_eq!(cpu.output_str(), "4,6,3,5,6,3,5,2,1,0");
+    }
+}
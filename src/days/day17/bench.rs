@@ -0,0 +1,140 @@
+use std::time::{Duration, Instant};
+
+use super::{Compiled, ExecError, CPU};
+
+/**
+ * How long one way of running the program took over every A.
+ */
+#[derive(Clone, Debug)]
+pub struct Measurement {
+    pub name: &'static str,
+    pub elapsed: Duration,
+}
+
+/**
+ * The smallest A that outputs as many values as the program is long, which is where the part 2
+ * search spends its time. 0 if that doesn't fit in a register.
+ */
+pub fn first_a(program: &[usize]) -> usize {
+    8usize
+        .checked_pow(program.len().saturating_sub(1) as u32)
+        .unwrap_or(0)
+}
+
+/**
+ * Runs the program with `runs` values of A counting up from `start`: with `CPU::execute`, then
+ * compiled, then compiled but only checking whether it outputs itself (what a brute-force part 2
+ * would do). Errors with the first A the interpreter and the compiled program disagree on.
+ */
+pub fn compare(cpu: &CPU, start: usize, runs: usize) -> Result<Vec<Measurement>, String> {
+    let [_, b, c] = cpu.regs();
+    let a_values = || (start..).take(runs);
+
+    let timer = Instant::now();
+    let interpreted: Vec<Result<String, ExecError>> = a_values()
+        .map(|a| {
+            let mut cpu = cpu.clone();
+            cpu.set_reg(0, a);
+            cpu.execute(false)
+        })
+        .collect();
+    let interpreted_time = timer.elapsed();
+
+    let timer = Instant::now();
+    let compiled = Compiled::new(cpu.program());
+    let outputs: Vec<Result<Vec<usize>, ExecError>> =
+        a_values().map(|a| compiled.run([a, b, c], None)).collect();
+    let compiled_time = timer.elapsed();
+
+    let timer = Instant::now();
+    let compiled = Compiled::new(cpu.program());
+    let quines = a_values()
+        .filter(|&a| compiled.outputs([a, b, c], cpu.program()))
+        .count();
+    let checking_time = timer.elapsed();
+
+    for (a, (slow, fast)) in a_values().zip(interpreted.iter().zip(&outputs)) {
+        let fast = fast.clone().map(|out| {
+            let out: Vec<String> = out.iter().map(|v| v.to_string()).collect();
+            out.join(",")
+        });
+        if fast != *slow {
+            return Err(format!(
+                "A = {:#o}: interpreted {:?}, compiled {:?}",
+                a, slow, fast
+            ));
+        }
+    }
+    let expected = outputs
+        .iter()
+        .filter(|out| out.as_ref().is_ok_and(|out| out == cpu.program()))
+        .count();
+    if quines != expected {
+        return Err(format!(
+            "{} values of A output the program, but checking found {}",
+            expected, quines
+        ));
+    }
+
+    Ok(vec![
+        Measurement {
+            name: "interpreted",
+            elapsed: interpreted_time,
+        },
+        Measurement {
+            name: "compiled",
+            elapsed: compiled_time,
+        },
+        Measurement {
+            name: "compiled, checking",
+            elapsed: checking_time,
+        },
+    ])
+}
+
+/**
+ * `compare` as a table, with each way's speedup over the interpreter.
+ */
+pub fn report(cpu: &CPU, start: usize, runs: usize) -> Result<String, String> {
+    let measurements = compare(cpu, start, runs)?;
+    let baseline = measurements[0].elapsed.as_secs_f64();
+    let mut out = format!(
+        "{} runs, A from {:#o}\n{:<20} {:>12} {:>12} {:>8}\n",
+        runs, start, "method", "time", "per run", "speedup"
+    );
+    for m in &measurements {
+        out += &format!(
+            "{:<20} {:>12.2?} {:>12.2?} {:>7.1}x\n",
+            m.name,
+            m.elapsed,
+            m.elapsed / runs.max(1) as u32,
+            baseline / m.elapsed.as_secs_f64().max(f64::MIN_POSITIVE)
+        );
+    }
+    Ok(out)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_report() {
+        let cpu = CPU::new([0, 0, 0], vec![0, 3, 5, 4, 3, 0]);
+        assert_eq!(first_a(cpu.program()), 0o100000);
+        // the part 2 example outputs itself with A = 117440
+        let measurements = compare(&cpu, 117430, 20).unwrap();
+        assert_eq!(measurements.len(), 3);
+
+        let table = report(&cpu, first_a(cpu.program()), 10).unwrap();
+        assert!(table.starts_with("10 runs, A from 0o100000\n"));
+        assert_eq!(table.lines().count(), 5);
+    }
+
+    #[test]
+    fn test_errors_match() {
+        // out 7 fails the same way either way
+        let cpu = CPU::new([0, 0, 0], vec![5, 7]);
+        assert!(compare(&cpu, 0, 5).is_ok());
+    }
+}
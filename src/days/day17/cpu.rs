@@ -0,0 +1,373 @@
+use std::fmt;
+
+use regex::Regex;
+
+use super::register::Register;
+use crate::*;
+
+pub const INSN_NAMES: [&str; 8] = ["adv", "bxl", "bst", "jnz", "bxc", "out", "bdv", "cdv"];
+
+/**
+ * Why a program stopped before halting.
+ */
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ExecError {
+    /**
+     * Combo operand 7 is reserved and can't be read.
+     */
+    InvalidCombo {
+        pc: usize,
+    },
+    /**
+     * A division shifted A by at least the register width. Mathematically that's 0, but it
+     * almost always means the program (or the register mode) isn't what was meant.
+     */
+    ShiftOverflow {
+        pc: usize,
+        shift: String,
+    },
+    StepLimit {
+        limit: usize,
+    },
+    /**
+     * The machine came back to a pc and registers it had already been in, so it never halts.
+     */
+    InfiniteLoop {
+        pc: usize,
+    },
+}
+
+impl fmt::Display for ExecError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ExecError::InvalidCombo { pc } => {
+                write!(f, "combo operand 7 is reserved (instruction at {})", pc)
+            }
+            ExecError::ShiftOverflow { pc, shift } => write!(
+                f,
+                "shift by {} is wider than the registers (instruction at {})",
+                shift, pc
+            ),
+            ExecError::StepLimit { limit } => {
+                write!(f, "still running after {} instructions", limit)
+            }
+            ExecError::InfiniteLoop { pc } => {
+                write!(f, "stuck in a loop that never halts (at {})", pc)
+            }
+        }
+    }
+}
+
+impl std::error::Error for ExecError {}
+
+/**
+ * Brent's cycle detection: remembers one past state at a time, and swaps in the current one at
+ * every power of two steps. Any cycle gets noticed within about twice its start plus length.
+ */
+pub(super) struct LoopDetector<S> {
+    saved: Option<S>,
+    power: usize,
+    since_saved: usize,
+}
+
+impl<S: Eq + Clone> LoopDetector<S> {
+    pub fn new() -> Self {
+        LoopDetector {
+            saved: None,
+            power: 1,
+            since_saved: 0,
+        }
+    }
+
+    /**
+     * Call with each state in turn. True once the states have started repeating.
+     */
+    pub fn repeats(&mut self, state: &S) -> bool {
+        if self.saved.as_ref() == Some(state) {
+            return true;
+        }
+        self.since_saved += 1;
+        if self.saved.is_none() || self.since_saved == self.power {
+            self.saved = Some(state.clone());
+            self.power *= 2;
+            self.since_saved = 0;
+        }
+        false
+    }
+}
+
+/**
+ * The 3-bit machine. Registers are `usize` unless asked for something wider, see `Register`.
+ */
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CPU<R = usize> {
+    regs: [R; 3],
+    imem: Vec<usize>,
+    pc: usize,
+    output: Vec<usize>,
+    insns_executed: usize,
+}
+
+impl CPU {
+    pub fn new(regs: [usize; 3], imem: Vec<usize>) -> Self {
+        Self::with_registers(regs, imem)
+    }
+
+    pub(crate) fn from_str(input: &str) -> Self {
+        Self::parse(input)
+            .expect("expected `Register A: ...` lines, a blank line, then `Program: ...`")
+    }
+}
+
+impl<R: Register> CPU<R> {
+    /**
+     * `new` for any register type, e.g. `CPU::<u128>::with_registers`.
+     */
+    pub fn with_registers(regs: [R; 3], imem: Vec<usize>) -> Self {
+        CPU {
+            regs,
+            imem,
+            pc: 0,
+            output: Vec::new(),
+            insns_executed: 0,
+        }
+    }
+
+    /**
+     * Reads the puzzle's `Register A: ...` / `Program: ...` format, or None if it doesn't match
+     * (or a register doesn't fit in `R`).
+     */
+    pub fn parse(input: &str) -> Option<Self> {
+        let input = normalize_input(input);
+        let sections = split_sections(&input);
+        let re =
+            Regex::new(r"Register A: (?P<a>\d+)\nRegister B: (?P<b>\d+)\nRegister C: (?P<c>\d+)")
+                .unwrap();
+        let caps = re.captures(sections.first()?)?;
+        let a = caps.name("a")?.as_str().parse::<R>().ok()?;
+        let b = caps.name("b")?.as_str().parse::<R>().ok()?;
+        let c = caps.name("c")?.as_str().parse::<R>().ok()?;
+        let regs = [a, b, c];
+
+        let program_re = Regex::new(r"Program: (?P<insns>[\d,]+)").unwrap();
+        let insn_input = program_re
+            .captures(sections.get(1)?)?
+            .name("insns")?
+            .as_str();
+        let insns = parse_row_major::<usize>(insn_input, ",").pop()?;
+        if insns.iter().any(|&x| x > 7) {
+            return None;
+        }
+        Some(Self::with_registers(regs, insns))
+    }
+
+    /**
+     * The same machine and state with 128 bit registers.
+     */
+    pub fn widen(&self) -> CPU<u128> {
+        CPU {
+            regs: self.regs.map(R::to_u128),
+            imem: self.imem.clone(),
+            pc: self.pc,
+            output: self.output.clone(),
+            insns_executed: self.insns_executed,
+        }
+    }
+
+    pub fn regs(&self) -> [R; 3] {
+        self.regs
+    }
+
+    /**
+     * Register 0, 1, 2 is A, B, C.
+     */
+    pub fn set_reg(&mut self, reg: usize, value: R) {
+        self.regs[reg] = value;
+    }
+
+    pub fn pc(&self) -> usize {
+        self.pc
+    }
+
+    pub fn program(&self) -> &[usize] {
+        &self.imem
+    }
+
+    pub fn output(&self) -> &[usize] {
+        &self.output
+    }
+
+    /**
+     * Instructions executed so far.
+     */
+    pub fn steps(&self) -> usize {
+        self.insns_executed
+    }
+
+    /**
+     * The pc has run off the end of the program (an opcode without an operand counts too).
+     */
+    pub fn halted(&self) -> bool {
+        self.pc + 1 >= self.imem.len()
+    }
+
+    /**
+     * Opcode and operand about to run.
+     */
+    pub fn current_insn(&self) -> Option<(usize, usize)> {
+        if self.halted() {
+            return None;
+        }
+        Some((self.imem[self.pc], self.imem[self.pc + 1]))
+    }
+
+    fn combo(&self, value: usize) -> Result<R, ExecError> {
+        match value {
+            0..=3 => Ok(R::from_small(value)),
+            4 => Ok(self.regs[0]),
+            5 => Ok(self.regs[1]),
+            6 => Ok(self.regs[2]),
+            _ => Err(ExecError::InvalidCombo { pc: self.pc }),
+        }
+    }
+
+    fn print_regfile(&self) {
+        println!(
+            "\tA: {:o} B: {:o} C: {:o}",
+            self.regs[0], self.regs[1], self.regs[2]
+        );
+    }
+
+    fn print_step(&self) {
+        if let Some((opcode, operand)) = self.current_insn() {
+            println!("{}: {} {}", self.pc, INSN_NAMES[opcode], operand);
+        }
+    }
+
+    /**
+     * Runs one instruction. Ok(false) if already halted. On an error nothing changes.
+     */
+    pub fn step(&mut self) -> Result<bool, ExecError> {
+        let Some((opcode, operand)) = self.current_insn() else {
+            return Ok(false);
+        };
+        let mut advance_pc = true;
+
+        match opcode {
+            0 => self.div(operand, 0)?,
+            1 => self.bxl(operand),
+            2 => self.bst(operand)?,
+            3 => advance_pc = self.jnz(operand),
+            4 => self.bxc(),
+            5 => self.out(operand)?,
+            6 => self.div(operand, 1)?,
+            7 => self.div(operand, 2)?,
+            _ => unreachable!("parse only accepts 3-bit values"),
+        }
+
+        if advance_pc {
+            self.pc += 2;
+        }
+        self.insns_executed += 1;
+
+        Ok(true)
+    }
+
+    /**
+     * Steps until the program halts. Gives up after `max_steps` more instructions if there's a
+     * limit, and as soon as it's clear the program loops forever.
+     */
+    pub fn run(&mut self, max_steps: Option<usize>) -> Result<(), ExecError> {
+        let mut detector = LoopDetector::new();
+        let mut steps = 0;
+        while !self.halted() {
+            if detector.repeats(&(self.pc, self.regs)) {
+                return Err(ExecError::InfiniteLoop { pc: self.pc });
+            }
+            if max_steps == Some(steps) {
+                return Err(ExecError::StepLimit { limit: steps });
+            }
+            self.step()?;
+            steps += 1;
+        }
+        Ok(())
+    }
+
+    /**
+     * Runs to the end and returns the output, printing every step if `verbose`.
+     */
+    pub fn execute(&mut self, verbose: bool) -> Result<String, ExecError> {
+        if !verbose {
+            self.run(None)?;
+            return Ok(self.output_str());
+        }
+        let mut detector = LoopDetector::new();
+        self.print_regfile();
+        self.print_step();
+        while !self.halted() {
+            if detector.repeats(&(self.pc, self.regs)) {
+                return Err(ExecError::InfiniteLoop { pc: self.pc });
+            }
+            self.step()?;
+            self.print_regfile();
+            self.print_step();
+        }
+        Ok(self.output_str())
+    }
+
+    // [dest] = A / (2 ** combo(op))
+    fn div(&mut self, operand: usize, dest: usize) -> Result<(), ExecError> {
+        let shift = self.combo(operand)?;
+        self.regs[dest] =
+            self.regs[0]
+                .checked_shr(shift)
+                .ok_or_else(|| ExecError::ShiftOverflow {
+                    pc: self.pc,
+                    shift: shift.to_string(),
+                })?;
+        Ok(())
+    }
+
+    // B = combo(op) % 8
+    fn bst(&mut self, operand: usize) -> Result<(), ExecError> {
+        self.regs[1] = R::from_small(self.combo(operand)?.low3());
+        Ok(())
+    }
+
+    // if A != 0 {PC = op}
+    // returns true if no jump
+    fn jnz(&mut self, operand: usize) -> bool {
+        if self.regs[0] == R::ZERO {
+            return true;
+        }
+        self.pc = operand;
+        false
+    }
+
+    fn bxl(&mut self, operand: usize) {
+        self.regs[1] = self.regs[1].xor(R::from_small(operand));
+    }
+
+    fn bxc(&mut self) {
+        self.regs[1] = self.regs[1].xor(self.regs[2]);
+    }
+
+    // pushes combo result modulo 8 to output
+    fn out(&mut self, operand: usize) -> Result<(), ExecError> {
+        self.output.push(self.combo(operand)?.low3());
+        Ok(())
+    }
+
+    pub fn output_str(&self) -> String {
+        let mut ret = String::new();
+        if self.output.is_empty() {
+            return ret;
+        }
+        for i in 0..(self.output.len() - 1) {
+            ret.push_str(self.output[i].to_string().as_str());
+            ret.push(',');
+        }
+        ret.push_str(self.output[self.output.len() - 1].to_string().as_str());
+        ret
+    }
+}
@@ -0,0 +1,552 @@
+use std::collections::{BTreeSet, VecDeque};
+
+use super::asm::describe;
+use super::cpu::LoopDetector;
+use super::{ExecError, CPU};
+
+/**
+ * How many past states `back` can return to. Each one is a full CPU clone, and `continue` can run
+ * for millions of steps.
+ */
+const HISTORY_LIMIT: usize = 100_000;
+
+/**
+ * `continue` gives up after this many instructions, in case the program never halts.
+ */
+const CONTINUE_LIMIT: usize = 10_000_000;
+
+const REG_NAMES: [char; 3] = ['A', 'B', 'C'];
+
+pub const HELP: &str = "\
+step [n]            (s)  run n instructions, default 1
+continue            (c)  run until a breakpoint, watchpoint or halt
+break <pc>          (b)  stop before the instruction at pc
+delete <pc>              remove a breakpoint
+watch <a|b|c>       (w)  stop whenever the register changes
+unwatch <a|b|c>          stop watching a register
+output [n]          (o)  stop once n values have been output, no n to clear
+back [n]                 rewind n instructions, default 1
+regs [oct|dec|bin]  (r)  print the registers
+radix <oct|dec|bin>      pick how registers are printed
+set <a|b|c> <value>      change a register (0o, 0b, 0x prefixes work)
+reset [a]                start over, optionally with a new A
+list                (l)  show the program
+info                (i)  show breakpoints and watchpoints
+quit                (q)
+An empty line repeats the last command.";
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Radix {
+    Oct,
+    Dec,
+    Bin,
+}
+
+impl Radix {
+    fn parse(s: &str) -> Option<Self> {
+        match s {
+            "oct" | "o" | "8" => Some(Radix::Oct),
+            "dec" | "d" | "10" => Some(Radix::Dec),
+            "bin" | "b" | "2" => Some(Radix::Bin),
+            _ => None,
+        }
+    }
+
+    fn format(self, value: usize) -> String {
+        match self {
+            Radix::Oct => format!("0o{:o}", value),
+            Radix::Dec => value.to_string(),
+            Radix::Bin => format!("0b{:b}", value),
+        }
+    }
+}
+
+/**
+ * Interactive debugger state for one day17 program. Feed it lines with `command`.
+ */
+pub struct Debugger {
+    initial: CPU,
+    cpu: CPU,
+    history: VecDeque<CPU>,
+    breakpoints: BTreeSet<usize>,
+    watches: [bool; 3],
+    output_break: Option<usize>,
+    radix: Radix,
+    last_command: String,
+}
+
+impl Debugger {
+    pub fn new(cpu: CPU) -> Self {
+        Debugger {
+            initial: cpu.clone(),
+            cpu,
+            history: VecDeque::new(),
+            breakpoints: BTreeSet::new(),
+            watches: [false; 3],
+            output_break: None,
+            radix: Radix::Oct,
+            last_command: String::new(),
+        }
+    }
+
+    pub fn cpu(&self) -> &CPU {
+        &self.cpu
+    }
+
+    /**
+     * Runs one line of input and returns what to print, or None to quit.
+     */
+    pub fn command(&mut self, line: &str) -> Option<String> {
+        let line = line.trim();
+        let line = if line.is_empty() {
+            self.last_command.clone()
+        } else {
+            self.last_command = line.to_string();
+            line.to_string()
+        };
+        let words: Vec<&str> = line.split_whitespace().collect();
+        let Some((&cmd, args)) = words.split_first() else {
+            return Some(String::new());
+        };
+
+        let reply = match cmd {
+            "step" | "s" => match parse_count(args) {
+                Some(n) => self.step(n),
+                None => "usage: step [n]".to_string(),
+            },
+            "continue" | "c" => self.run_on(),
+            "break" | "b" => match args.first().and_then(|a| parse_value(a)) {
+                Some(pc) => {
+                    self.breakpoints.insert(pc);
+                    format!("breakpoint at pc {}", pc)
+                }
+                None => "usage: break <pc>".to_string(),
+            },
+            "delete" => match args.first().and_then(|a| parse_value(a)) {
+                Some(pc) if self.breakpoints.remove(&pc) => format!("removed breakpoint at {}", pc),
+                Some(pc) => format!("no breakpoint at {}", pc),
+                None => "usage: delete <pc>".to_string(),
+            },
+            "watch" | "w" | "unwatch" => match args.first().and_then(|a| parse_reg(a)) {
+                Some(reg) => {
+                    self.watches[reg] = cmd != "unwatch";
+                    let verb = if self.watches[reg] {
+                        "watching"
+                    } else {
+                        "stopped watching"
+                    };
+                    format!("{} {}", verb, REG_NAMES[reg])
+                }
+                None => format!("usage: {} <a|b|c>", cmd),
+            },
+            "output" | "o" => match args.first() {
+                None => {
+                    self.output_break = None;
+                    "cleared output breakpoint".to_string()
+                }
+                Some(n) => match parse_value(n) {
+                    Some(n) => {
+                        self.output_break = Some(n);
+                        format!("stopping once {} values are output", n)
+                    }
+                    None => "usage: output [n]".to_string(),
+                },
+            },
+            "back" => match parse_count(args) {
+                Some(n) => self.back(n),
+                None => "usage: back [n]".to_string(),
+            },
+            "regs" | "r" => match args.first() {
+                None => self.regs(self.radix),
+                Some(r) => match Radix::parse(r) {
+                    Some(radix) => self.regs(radix),
+                    None => "usage: regs [oct|dec|bin]".to_string(),
+                },
+            },
+            "radix" => match args.first().and_then(|r| Radix::parse(r)) {
+                Some(radix) => {
+                    self.radix = radix;
+                    self.regs(radix)
+                }
+                None => "usage: radix <oct|dec|bin>".to_string(),
+            },
+            "set" => match (
+                args.first().and_then(|a| parse_reg(a)),
+                args.get(1).and_then(|v| parse_value(v)),
+            ) {
+                (Some(reg), Some(value)) => {
+                    self.push_history();
+                    self.cpu.set_reg(reg, value);
+                    self.regs(self.radix)
+                }
+                _ => "usage: set <a|b|c> <value>".to_string(),
+            },
+            "reset" => {
+                self.cpu = self.initial.clone();
+                self.history.clear();
+                match args.first() {
+                    None => {}
+                    Some(a) => match parse_value(a) {
+                        Some(a) => self.cpu.set_reg(0, a),
+                        None => return Some("usage: reset [a]".to_string()),
+                    },
+                }
+                self.status()
+            }
+            "list" | "l" => self.list(),
+            "info" | "i" => self.info(),
+            "help" | "h" | "?" => HELP.to_string(),
+            "quit" | "q" | "exit" => return None,
+            _ => format!("unknown command `{}`, try `help`", cmd),
+        };
+        Some(reply)
+    }
+
+    /**
+     * One line summary: next instruction, registers and output so far.
+     */
+    pub fn status(&self) -> String {
+        let insn = match self.cpu.current_insn() {
+            Some((opcode, operand)) => {
+                format!("pc {}: {}", self.cpu.pc(), describe(opcode, operand))
+            }
+            None => format!("pc {}: halted", self.cpu.pc()),
+        };
+        format!(
+            "{} | {} | out: {}",
+            insn,
+            self.regs(self.radix),
+            self.cpu.output_str()
+        )
+    }
+
+    fn regs(&self, radix: Radix) -> String {
+        let regs = self.cpu.regs();
+        (0..3)
+            .map(|r| format!("{}: {}", REG_NAMES[r], radix.format(regs[r])))
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+
+    fn push_history(&mut self) {
+        if self.history.len() == HISTORY_LIMIT {
+            self.history.pop_front();
+        }
+        self.history.push_back(self.cpu.clone());
+    }
+
+    fn single_step(&mut self) -> Result<bool, ExecError> {
+        if self.cpu.halted() {
+            return Ok(false);
+        }
+        self.push_history();
+        let stepped = self.cpu.step();
+        if stepped.is_err() {
+            // the CPU didn't change, so there's nothing to rewind to
+            self.history.pop_back();
+        }
+        stepped
+    }
+
+    /**
+     * Steps n times, stopping early at a breakpoint, a watchpoint or the output breakpoint.
+     */
+    fn step(&mut self, n: usize) -> String {
+        for _ in 0..n {
+            let before = self.cpu.regs();
+            let output_len = self.cpu.output().len();
+            match self.single_step() {
+                Ok(true) => {}
+                Ok(false) => break,
+                Err(e) => return format!("error: {}\n{}", e, self.status()),
+            }
+            if let Some(reason) = self.stop_reason(before, output_len) {
+                return reason;
+            }
+        }
+        self.status()
+    }
+
+    /**
+     * Why the last step should stop a run, given the registers and output length before it.
+     */
+    fn stop_reason(&self, before: [usize; 3], output_len: usize) -> Option<String> {
+        let regs = self.cpu.regs();
+        for r in 0..3 {
+            if self.watches[r] && regs[r] != before[r] {
+                return Some(format!(
+                    "{} changed: {} -> {}\n{}",
+                    REG_NAMES[r],
+                    self.radix.format(before[r]),
+                    self.radix.format(regs[r]),
+                    self.status()
+                ));
+            }
+        }
+        let new_len = self.cpu.output().len();
+        if new_len != output_len && self.output_break == Some(new_len) {
+            return Some(format!("{} values output\n{}", new_len, self.status()));
+        }
+        if self.breakpoints.contains(&self.cpu.pc()) {
+            return Some(format!(
+                "breakpoint at pc {}\n{}",
+                self.cpu.pc(),
+                self.status()
+            ));
+        }
+        None
+    }
+
+    /**
+     * Steps until something asks to stop. Always makes at least one step, so continuing from a
+     * breakpoint moves past it.
+     */
+    fn run_on(&mut self) -> String {
+        let mut detector = LoopDetector::new();
+        for _ in 0..CONTINUE_LIMIT {
+            let before = self.cpu.regs();
+            let output_len = self.cpu.output().len();
+            match self.single_step() {
+                Ok(true) => {}
+                Ok(false) => return format!("halted\n{}", self.status()),
+                Err(e) => return format!("error: {}\n{}", e, self.status()),
+            }
+
+            if let Some(reason) = self.stop_reason(before, output_len) {
+                return reason;
+            }
+            if self.cpu.halted() {
+                return format!("halted\n{}", self.status());
+            }
+            if detector.repeats(&(self.cpu.pc(), self.cpu.regs())) {
+                let e = ExecError::InfiniteLoop { pc: self.cpu.pc() };
+                return format!("{}, stopping\n{}", e, self.status());
+            }
+        }
+        let e = ExecError::StepLimit {
+            limit: CONTINUE_LIMIT,
+        };
+        format!("{}, stopping\n{}", e, self.status())
+    }
+
+    fn back(&mut self, n: usize) -> String {
+        let mut rewound = 0;
+        while rewound < n {
+            match self.history.pop_back() {
+                Some(prev) => self.cpu = prev,
+                None => break,
+            }
+            rewound += 1;
+        }
+        if rewound < n {
+            format!("only {} steps of history\n{}", rewound, self.status())
+        } else {
+            self.status()
+        }
+    }
+
+    fn list(&self) -> String {
+        let program = self.cpu.program();
+        let mut lines = Vec::new();
+        for pc in (0..program.len().saturating_sub(1)).step_by(2) {
+            let marker = if pc == self.cpu.pc() { "=>" } else { "  " };
+            let bp = if self.breakpoints.contains(&pc) {
+                "*"
+            } else {
+                " "
+            };
+            lines.push(format!(
+                "{}{}{:3}: {}",
+                marker,
+                bp,
+                pc,
+                describe(program[pc], program[pc + 1])
+            ));
+        }
+        lines.join("\n")
+    }
+
+    fn info(&self) -> String {
+        let breaks: Vec<String> = self.breakpoints.iter().map(|pc| pc.to_string()).collect();
+        let watches: Vec<String> = (0..3)
+            .filter(|&r| self.watches[r])
+            .map(|r| REG_NAMES[r].to_string())
+            .collect();
+        let output = match self.output_break {
+            Some(n) => n.to_string(),
+            None => "none".to_string(),
+        };
+        format!(
+            "breakpoints: {}\nwatching: {}\noutput breakpoint: {}\nhistory: {} steps",
+            if breaks.is_empty() {
+                "none".to_string()
+            } else {
+                breaks.join(", ")
+            },
+            if watches.is_empty() {
+                "none".to_string()
+            } else {
+                watches.join(", ")
+            },
+            output,
+            self.history.len()
+        )
+    }
+}
+
+fn parse_count(args: &[&str]) -> Option<usize> {
+    match args.first() {
+        None => Some(1),
+        Some(n) => parse_value(n),
+    }
+}
+
+fn parse_reg(s: &str) -> Option<usize> {
+    match s {
+        "a" | "A" => Some(0),
+        "b" | "B" => Some(1),
+        "c" | "C" => Some(2),
+        _ => None,
+    }
+}
+
+/**
+ * Decimal, or octal/binary/hex with a 0o/0b/0x prefix.
+ */
+pub fn parse_value(s: &str) -> Option<usize> {
+    let (digits, radix) = match s.get(..2) {
+        Some("0o") => (&s[2..], 8),
+        Some("0b") => (&s[2..], 2),
+        Some("0x") => (&s[2..], 16),
+        _ => (s, 10),
+    };
+    usize::from_str_radix(digits, radix).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    static TEST_INPUT: &str = "Register A: 729
+Register B: 0
+Register C: 0
+
+Program: 0,1,5,4,3,0";
+
+    fn debugger() -> Debugger {
+        Debugger::new(CPU::from_str(TEST_INPUT))
+    }
+
+    #[test]
+    fn test_step_and_back() {
+        let mut dbg = debugger();
+        dbg.command("step 2");
+        assert_eq!(dbg.cpu().pc(), 4);
+        assert_eq!(dbg.cpu().output(), &[4]);
+        // an empty line repeats `step 2`: jnz back to 0, then adv again
+        dbg.command("");
+        assert_eq!(dbg.cpu().pc(), 2);
+        assert_eq!(dbg.cpu().regs()[0], 729 >> 2);
+        dbg.command("back 3");
+        assert_eq!(dbg.cpu().pc(), 2);
+        assert_eq!(dbg.cpu().regs()[0], 729 >> 1);
+        assert!(dbg.command("back 5").unwrap().starts_with("only 1 steps"));
+        assert_eq!(dbg.cpu(), &CPU::from_str(TEST_INPUT));
+    }
+
+    #[test]
+    fn test_continue_to_halt() {
+        let mut dbg = debugger();
+        let reply = dbg.command("c").unwrap();
+        assert!(reply.starts_with("halted"));
+        assert_eq!(dbg.cpu().output_str(), "4,6,3,5,6,3,5,2,1,0");
+    }
+
+    #[test]
+    fn test_breakpoints() {
+        let mut dbg = debugger();
+        dbg.command("b 4");
+        assert!(dbg.command("c").unwrap().starts_with("breakpoint at pc 4"));
+        // continuing from a breakpoint runs the loop once more and stops there again
+        dbg.command("c");
+        assert_eq!(dbg.cpu().pc(), 4);
+        assert_eq!(dbg.cpu().output().len(), 2);
+        dbg.command("delete 4");
+        assert!(dbg.command("c").unwrap().starts_with("halted"));
+    }
+
+    #[test]
+    fn test_watch_and_output_break() {
+        let mut dbg = debugger();
+        dbg.command("watch a");
+        let reply = dbg.command("c").unwrap();
+        assert!(reply.starts_with("A changed: 0o1331 -> 0o554"), "{}", reply);
+        dbg.command("unwatch a");
+        dbg.command("o 3");
+        assert!(dbg.command("c").unwrap().starts_with("3 values output"));
+        assert_eq!(dbg.cpu().output_str(), "4,6,3");
+    }
+
+    #[test]
+    fn test_step_stops_early() {
+        let mut dbg = debugger();
+        dbg.command("watch b");
+        // B never changes, so this runs the whole program
+        dbg.command("s 50");
+        assert!(dbg.cpu().halted());
+
+        dbg.command("reset");
+        dbg.command("watch a");
+        let reply = dbg.command("s 50").unwrap();
+        assert!(reply.starts_with("A changed: 0o1331 -> 0o554"), "{}", reply);
+        assert_eq!(dbg.cpu().steps(), 1);
+
+        dbg.command("unwatch a");
+        dbg.command("b 4");
+        assert!(dbg
+            .command("s 50")
+            .unwrap()
+            .starts_with("breakpoint at pc 4"));
+        assert_eq!(dbg.cpu().steps(), 2);
+        dbg.command("delete 4");
+        dbg.command("o 2");
+        assert!(dbg.command("s 50").unwrap().starts_with("2 values output"));
+        assert_eq!(dbg.cpu().output().len(), 2);
+    }
+
+    #[test]
+    fn test_errors() {
+        let mut dbg = Debugger::new(CPU::new([1, 0, 0], vec![1, 1, 3, 0]));
+        assert!(dbg
+            .command("c")
+            .unwrap()
+            .starts_with("stuck in a loop that never halts"));
+
+        let mut dbg = Debugger::new(CPU::new([1, 0, 0], vec![2, 4, 5, 7]));
+        dbg.command("s");
+        let reply = dbg.command("s").unwrap();
+        assert!(
+            reply.starts_with("error: combo operand 7 is reserved"),
+            "{}",
+            reply
+        );
+        assert_eq!(dbg.cpu().pc(), 2);
+        dbg.command("back");
+        assert_eq!(dbg.cpu().pc(), 0);
+    }
+
+    #[test]
+    fn test_regs_and_set() {
+        let mut dbg = debugger();
+        assert_eq!(dbg.command("r dec").unwrap(), "A: 729 B: 0 C: 0");
+        assert_eq!(
+            dbg.command("r bin").unwrap(),
+            "A: 0b1011011001 B: 0b0 C: 0b0"
+        );
+        dbg.command("set b 0o17");
+        assert_eq!(dbg.cpu().regs()[1], 15);
+        dbg.command("reset 0o100");
+        assert_eq!(dbg.cpu().regs(), [64, 0, 0]);
+        assert!(dbg.command("quit").is_none());
+    }
+}
@@ -0,0 +1,130 @@
+use crate::error::Error;
+use crate::solution::{Solution, Variant};
+
+pub mod asm;
+pub mod bench;
+pub mod compile;
+mod cpu;
+pub mod debugger;
+pub mod quine;
+mod register;
+pub mod trace;
+
+pub use compile::Compiled;
+pub use cpu::{ExecError, CPU, INSN_NAMES};
+pub use register::Register;
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    type Input = CPU;
+
+    fn day(&self) -> i32 {
+        17
+    }
+
+    fn title(&self) -> &'static str {
+        "Chronospatial Computer"
+    }
+
+    fn parse(&self, input: &str) -> Result<Self::Input, Error> {
+        Ok(CPU::from_str(input))
+    }
+
+    fn part1(&self, input: &Self::Input) -> String {
+        part1(input)
+    }
+
+    fn part2(&self, input: &Self::Input) -> String {
+        part2(input)
+    }
+
+    fn variants(&self) -> Vec<Variant<Self::Input>> {
+        vec![Variant::new("fast", part1fast)]
+    }
+}
+
+fn part1(cpu: &CPU) -> String {
+    let mut cpu = cpu.clone();
+    match cpu.execute(false) {
+        Ok(output) => output,
+        Err(e) => e.to_string(),
+    }
+}
+
+fn part1fast(cpu: &CPU) -> String {
+    match Compiled::new(cpu.program()).run(cpu.regs(), None) {
+        Ok(output) => {
+            let output: Vec<String> = output.iter().map(|v| v.to_string()).collect();
+            output.join(",")
+        }
+        Err(e) => e.to_string(),
+    }
+}
+
+// Originally this only worked for my own input, with the loop body hard-coded. Now it checks the
+// program is a "loop while A != 0, shift A by 3, output once" program and runs the real CPU to
+// pick A one octal digit at a time.
+fn part2(cpu: &CPU) -> String {
+    match quine::solve(cpu) {
+        Ok(a) => a.to_string(),
+        Err(e) => e.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    static TEST_INPUT: &str = "Register A: 729
+Register B: 0
+Register C: 0
+
+Program: 0,1,5,4,3,0";
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(part1(&CPU::from_str(TEST_INPUT)), "4,6,3,5,6,3,5,2,1,0");
+    }
+
+    #[test]
+    fn test_part1fast() {
+        assert_eq!(
+            part1fast(&CPU::from_str(TEST_INPUT)),
+            part1(&CPU::from_str(TEST_INPUT))
+        );
+    }
+
+    #[test]
+    fn test_exec_errors() {
+        let mut cpu = CPU::new([1, 64, 0], vec![0, 5]);
+        assert_eq!(
+            cpu.run(None),
+            Err(ExecError::ShiftOverflow {
+                pc: 0,
+                shift: "64".to_string()
+            })
+        );
+        // the wide registers take the same shift fine
+        let mut wide = CPU::<u128>::with_registers([1, 64, 0], vec![0, 5]);
+        assert_eq!(wide.run(None), Ok(()));
+
+        let mut cpu = CPU::new([1, 0, 0], vec![5, 7]);
+        assert_eq!(cpu.run(None), Err(ExecError::InvalidCombo { pc: 0 }));
+        assert_eq!(cpu.pc(), 0);
+
+        let mut cpu = CPU::new([1, 0, 0], vec![1, 1, 3, 0]);
+        assert_eq!(cpu.run(None), Err(ExecError::InfiniteLoop { pc: 0 }));
+        let mut cpu = CPU::new([1 << 40, 0, 0], vec![0, 1, 3, 0]);
+        assert_eq!(cpu.run(Some(10)), Err(ExecError::StepLimit { limit: 10 }));
+        assert_eq!(cpu.run(None), Ok(()));
+    }
+
+    #[test]
+    fn test_parse_wide() {
+        let input = TEST_INPUT.replace("729", "340282366920938463463374607431768211455");
+        assert!(CPU::<usize>::parse(&input).is_none());
+        let wide = CPU::<u128>::parse(&input).unwrap();
+        assert_eq!(wide.regs()[0], u128::MAX);
+    }
+}
@@ -0,0 +1,245 @@
+use std::fmt;
+
+use super::asm::describe;
+use super::{Register, CPU};
+
+const ADV: usize = 0;
+const JNZ: usize = 3;
+const OUT: usize = 5;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum QuineError {
+    /**
+     * The program isn't a "loop while A != 0, shift A by 3, output once" program, so searching one
+     * octal digit at a time wouldn't be sound.
+     */
+    Unsupported(String),
+    /**
+     * The program has the right shape but no A makes it print itself.
+     */
+    NoSolution,
+}
+
+impl fmt::Display for QuineError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            QuineError::Unsupported(reason) => {
+                write!(f, "can't solve this program for a quine: {}", reason)
+            }
+            QuineError::NoSolution => write!(f, "no value of A makes this program print itself"),
+        }
+    }
+}
+
+impl std::error::Error for QuineError {}
+
+/**
+ * Checks that one pass of the loop body turns A into A / 8 and outputs a value that depends only
+ * on A at the start of the pass. Then A's top octal digit decides the last output, the next digit
+ * (with the one above it) the one before that, and so on.
+ */
+pub fn check_shape(program: &[usize]) -> Result<(), QuineError> {
+    let unsupported = |reason: String| Err(QuineError::Unsupported(reason));
+    if program.is_empty() || program.len() % 2 == 1 {
+        return unsupported(format!(
+            "the program has {} values, not a whole number of instructions",
+            program.len()
+        ));
+    }
+    if program.len() * 3 > u128::BITS as usize {
+        return unsupported(format!(
+            "{} outputs would need an A wider than {} bits",
+            program.len(),
+            u128::BITS
+        ));
+    }
+
+    let body = &program[..program.len() - 2];
+    if program[program.len() - 2..] != [JNZ, 0] {
+        return unsupported("it doesn't end with `jnz 0`".to_string());
+    }
+
+    let mut shifts = 0;
+    let mut outs = 0;
+    // B and C must be set in each pass before they're used, or they'd carry state between passes
+    let mut written = [true, false, false];
+    for (i, insn) in body.chunks(2).enumerate() {
+        let (opcode, operand) = (insn[0], insn[1]);
+        let at = || format!("`{}` at {}", describe(opcode, operand), 2 * i);
+        let combo = !matches!(opcode, 1 | 3 | 4);
+
+        if combo && operand == 7 {
+            return unsupported(format!("{} uses the reserved combo operand", at()));
+        }
+        let mut reads = Vec::new();
+        if combo && (4..=6).contains(&operand) {
+            reads.push(operand - 4);
+        }
+        match opcode {
+            1 => reads.push(1),
+            4 => reads.extend([1, 2]),
+            _ => {}
+        }
+        if let Some(&reg) = reads.iter().find(|&&reg| !written[reg]) {
+            let name = ['A', 'B', 'C'][reg];
+            return unsupported(format!(
+                "{} reads {} before the loop sets it, so passes depend on each other",
+                at(),
+                name
+            ));
+        }
+
+        match opcode {
+            ADV if operand == 3 => shifts += 1,
+            ADV => return unsupported(format!("{} shifts A by something other than 3", at())),
+            JNZ => return unsupported(format!("{} jumps from inside the loop", at())),
+            OUT => outs += 1,
+            2 | 6 => written[1] = true,
+            7 => written[2] = true,
+            _ => {}
+        }
+    }
+
+    if shifts != 1 {
+        return unsupported(format!(
+            "A is shifted by 3 {} times per pass, not once",
+            shifts
+        ));
+    }
+    if outs != 1 {
+        return unsupported(format!("it outputs {} values per pass, not one", outs));
+    }
+    Ok(())
+}
+
+/**
+ * The smallest A that makes the program output itself. B and C start at whatever `cpu` has.
+ * Programs longer than 21 values can need more than 64 bits of A, so if A runs out of bits the
+ * search starts over with `u128` registers.
+ */
+pub fn solve(cpu: &CPU) -> Result<u128, QuineError> {
+    check_shape(cpu.program())?;
+    let found = match search(cpu, 0, 0) {
+        Ok(found) => found.map(|a| a as u128),
+        Err(Overflow) => search(&cpu.widen(), 0, 0).map_err(|Overflow| {
+            QuineError::Unsupported(format!("A needs more than {} bits", u128::BITS))
+        })?,
+    };
+    found.ok_or(QuineError::NoSolution)
+}
+
+/**
+ * The next octal digit of A didn't fit in the register.
+ */
+struct Overflow;
+
+/**
+ * `a` holds the top `digits` octal digits of the answer, and running it already prints the last
+ * `digits` values of the program. Tries each next digit, smallest first, so the first complete
+ * answer is the smallest.
+ */
+fn search<R: Register>(cpu: &CPU<R>, a: R, digits: usize) -> Result<Option<R>, Overflow> {
+    let program = cpu.program();
+    if digits == program.len() {
+        return Ok(Some(a));
+    }
+    // a leading 0 would just be a shorter A
+    let first = if digits == 0 { 1 } else { 0 };
+    let target = &program[program.len() - digits - 1..];
+    for digit in first..8 {
+        let candidate = a.push_octal(digit).ok_or(Overflow)?;
+        if run(cpu, candidate).is_some_and(|output| output == target) {
+            if let Some(found) = search(cpu, candidate, digits + 1)? {
+                return Ok(Some(found));
+            }
+        }
+    }
+    Ok(None)
+}
+
+/**
+ * Output for a given A, or None if it fails (a huge B or C can still overflow a shift).
+ */
+fn run<R: Register>(cpu: &CPU<R>, a: R) -> Option<Vec<usize>> {
+    let regs = cpu.regs();
+    let mut run = CPU::with_registers([a, regs[1], regs[2]], cpu.program().to_vec());
+    run.run(None).ok()?;
+    Some(run.output().to_vec())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::days::day17::asm::assemble;
+
+    fn cpu(program: &[usize]) -> CPU {
+        CPU::new([0, 0, 0], program.to_vec())
+    }
+
+    #[test]
+    fn test_example() {
+        let example = cpu(&[0, 3, 5, 4, 3, 0]);
+        assert_eq!(solve(&example), Ok(117440));
+        assert_eq!(run(&example, 117440).unwrap(), example.program());
+    }
+
+    #[test]
+    fn test_typical_input() {
+        // the usual shape of real inputs: B and C are rebuilt from A every pass
+        let program = assemble(
+            "\
+            bst A
+            bxl 7
+            cdv B
+            adv 3
+            bxl 7
+            bxc 1
+            out B
+            jnz 0",
+        )
+        .unwrap();
+        assert_eq!(program, [2, 4, 1, 7, 7, 5, 0, 3, 1, 7, 4, 1, 5, 5, 3, 0]);
+        let a = solve(&cpu(&program)).unwrap();
+        assert_eq!(run(&cpu(&program), a as usize).unwrap(), program);
+        // one digit fewer can't print enough values, so the answer has exactly 16 octal digits
+        assert_eq!(format!("{:o}", a).len(), 16);
+    }
+
+    #[test]
+    fn test_wide() {
+        // 24 outputs need a 72 bit A
+        let mut source = "bst A\nbxl 7\ncdv B\nadv 3\nbxl 7\nbxc 1\nout B\n".to_string();
+        source += &"bxl 0\n".repeat(4);
+        source += "jnz 0";
+        let program = assemble(&source).unwrap();
+        assert_eq!(program.len(), 24);
+        // 64 bits run out partway, and the search starts over with 128
+        assert!(matches!(search(&cpu(&program), 0, 0), Err(Overflow)));
+        let a = solve(&cpu(&program)).unwrap();
+        assert!(a > u64::MAX as u128);
+        let wide = CPU::<u128>::with_registers([0, 0, 0], program.clone());
+        assert_eq!(run(&wide, a).unwrap(), program);
+    }
+
+    #[test]
+    fn test_unsupported() {
+        let reason = |src: &str| match solve(&cpu(&assemble(src).unwrap())) {
+            Err(QuineError::Unsupported(reason)) => reason,
+            other => panic!("expected an unsupported shape, got {:?}", other),
+        };
+        assert!(reason("adv 3\nout A").contains("jnz 0"));
+        assert!(reason("adv 1\nout A\njnz 0").contains("other than 3"));
+        assert!(reason("adv 3\nout A\nout A\njnz 0").contains("2 values"));
+        assert!(reason("out A\njnz 0").contains("0 times"));
+        assert!(reason("bxl 1\nadv 3\nout B\njnz 0").contains("reads B"));
+        assert!(reason("bst A\nbxc\nadv 3\nout B\njnz 0").contains("reads C"));
+        assert!(reason("adv 3\njnz 0\nout A\njnz 0").contains("inside the loop"));
+    }
+
+    #[test]
+    fn test_no_solution() {
+        // always prints 7, so it can never print its own 0
+        let program = assemble("bst 3\nbxl 4\nout B\nadv 3\njnz 0").unwrap();
+        assert_eq!(solve(&cpu(&program)), Err(QuineError::NoSolution));
+    }
+}
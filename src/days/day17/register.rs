@@ -0,0 +1,93 @@
+use std::{fmt, hash::Hash, str::FromStr};
+
+/**
+ * What the CPU keeps in A, B and C. `usize` is the normal mode; `u128` is there for quine
+ * searches on programs too long for A to fit in 64 bits.
+ */
+pub trait Register:
+    Copy + Eq + Hash + fmt::Debug + fmt::Display + fmt::Octal + fmt::Binary + FromStr
+{
+    const BITS: u32;
+    const ZERO: Self;
+
+    /**
+     * A 3-bit literal or digit as a register value.
+     */
+    fn from_small(value: usize) -> Self;
+
+    /**
+     * The value mod 8.
+     */
+    fn low3(self) -> usize;
+
+    /**
+     * `self >> by`, or None when `by` is at least the register width.
+     */
+    fn checked_shr(self, by: Self) -> Option<Self>;
+
+    fn xor(self, other: Self) -> Self;
+
+    /**
+     * `self * 8 + digit`, for building A one octal digit at a time. None if the top digit would
+     * be shifted out.
+     */
+    fn push_octal(self, digit: usize) -> Option<Self>;
+
+    fn to_u128(self) -> u128;
+}
+
+macro_rules! impl_register {
+    ($($t:ty),*) => {$(
+        impl Register for $t {
+            const BITS: u32 = <$t>::BITS;
+            const ZERO: Self = 0;
+
+            fn from_small(value: usize) -> Self {
+                value as $t
+            }
+
+            fn low3(self) -> usize {
+                (self & 7) as usize
+            }
+
+            fn checked_shr(self, by: Self) -> Option<Self> {
+                u32::try_from(by).ok().and_then(|by| <$t>::checked_shr(self, by))
+            }
+
+            fn xor(self, other: Self) -> Self {
+                self ^ other
+            }
+
+            fn push_octal(self, digit: usize) -> Option<Self> {
+                if self.leading_zeros() < 3 {
+                    return None;
+                }
+                Some(self.checked_shl(3)? | digit as $t)
+            }
+
+            fn to_u128(self) -> u128 {
+                self as u128
+            }
+        }
+    )*};
+}
+
+impl_register!(usize, u64, u128);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_shifts() {
+        assert_eq!(Register::checked_shr(1usize << 40, 40usize), Some(1));
+        assert_eq!(Register::checked_shr(u64::MAX, 64u64), None);
+        assert_eq!(Register::checked_shr(1u128 << 100, 100u128), Some(1));
+        assert_eq!(Register::checked_shr(1u128, 1u128 << 40), None);
+        assert_eq!(0o17u128.push_octal(5), Some(0o175));
+        assert_eq!((1usize << 60).push_octal(7), Some((1 << 63) | 7));
+        assert_eq!((1usize << 61).push_octal(0), None);
+        assert_eq!((u128::MAX >> 3).push_octal(7), Some(u128::MAX));
+        assert_eq!((1u128 << 126).push_octal(0), None);
+    }
+}
@@ -0,0 +1,378 @@
+use std::fmt;
+
+use super::asm::describe;
+use super::cpu::LoopDetector;
+use super::{ExecError, Register, CPU};
+
+const CSV_HEADER: &str =
+    "step,pc,opcode,insn,operand,a_before,b_before,c_before,a_after,b_after,c_after,output,error";
+
+/**
+ * One executed instruction.
+ */
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TraceStep<R = usize> {
+    pub step: usize,
+    pub pc: usize,
+    pub opcode: usize,
+    pub operand: usize,
+    pub before: [R; 3],
+    pub after: [R; 3],
+    /**
+     * The value this instruction output, if it was an `out`.
+     */
+    pub output: Option<usize>,
+}
+
+/**
+ * Every instruction a run executed, and why it stopped if it didn't halt.
+ */
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Trace<R = usize> {
+    pub steps: Vec<TraceStep<R>>,
+    pub error: Option<ExecError>,
+    /**
+     * The registers when the run stopped.
+     */
+    pub regs: [R; 3],
+}
+
+/**
+ * Runs a copy of `cpu` to the end, recording each step.
+ */
+pub fn record<R: Register>(cpu: &CPU<R>, max_steps: Option<usize>) -> Trace<R> {
+    let mut cpu = cpu.clone();
+    let mut steps = Vec::new();
+    let mut detector = LoopDetector::new();
+    let mut error = None;
+    while let Some((opcode, operand)) = cpu.current_insn() {
+        if detector.repeats(&(cpu.pc(), cpu.regs())) {
+            error = Some(ExecError::InfiniteLoop { pc: cpu.pc() });
+            break;
+        }
+        if max_steps == Some(steps.len()) {
+            error = Some(ExecError::StepLimit { limit: steps.len() });
+            break;
+        }
+        let pc = cpu.pc();
+        let before = cpu.regs();
+        let output_len = cpu.output().len();
+        if let Err(e) = cpu.step() {
+            error = Some(e);
+            break;
+        }
+        steps.push(TraceStep {
+            step: steps.len(),
+            pc,
+            opcode,
+            operand,
+            before,
+            after: cpu.regs(),
+            output: cpu.output().get(output_len).copied(),
+        });
+    }
+    Trace {
+        steps,
+        error,
+        regs: cpu.regs(),
+    }
+}
+
+impl<R: Register> Trace<R> {
+    /**
+     * Everything the run output, in order.
+     */
+    pub fn output(&self) -> Vec<usize> {
+        self.steps.iter().filter_map(|s| s.output).collect()
+    }
+
+    /**
+     * One JSON object per line, one line per step, then an `{"error": ...}` line if the run
+     * failed.
+     */
+    pub fn to_jsonl(&self) -> String {
+        let mut out = String::new();
+        for s in &self.steps {
+            let output = match s.output {
+                Some(v) => v.to_string(),
+                None => "null".to_string(),
+            };
+            out += &format!(
+                "{{\"step\":{},\"pc\":{},\"opcode\":{},\"insn\":\"{}\",\"operand\":{},\"before\":[{},{},{}],\"after\":[{},{},{}],\"output\":{}}}\n",
+                s.step,
+                s.pc,
+                s.opcode,
+                describe(s.opcode, s.operand),
+                s.operand,
+                s.before[0],
+                s.before[1],
+                s.before[2],
+                s.after[0],
+                s.after[1],
+                s.after[2],
+                output
+            );
+        }
+        if let Some(e) = &self.error {
+            // messages are plain ASCII without quotes, but escape anyway
+            let message = e.to_string().replace('\\', "\\\\").replace('"', "\\\"");
+            out += &format!("{{\"error\":\"{}\"}}\n", message);
+        }
+        out
+    }
+
+    /**
+     * A header and one row per step. The `output` column is empty for steps that didn't output
+     * anything. If the run failed, a last row holds just the step it failed on and the error.
+     */
+    pub fn to_csv(&self) -> String {
+        let mut out = format!("{}\n", CSV_HEADER);
+        for s in &self.steps {
+            let output = s.output.map_or(String::new(), |v| v.to_string());
+            out += &format!(
+                "{},{},{},{},{},{},{},{},{},{},{},{},\n",
+                s.step,
+                s.pc,
+                s.opcode,
+                describe(s.opcode, s.operand),
+                s.operand,
+                s.before[0],
+                s.before[1],
+                s.before[2],
+                s.after[0],
+                s.after[1],
+                s.after[2],
+                output
+            );
+        }
+        if let Some(e) = &self.error {
+            let message = e.to_string().replace('"', "\"\"");
+            out += &format!("{},,,,,,,,,,,,\"{}\"\n", self.steps.len(), message);
+        }
+        out
+    }
+}
+
+/**
+ * How a run ended: its final registers, and the error if it didn't halt.
+ */
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Ending<R = usize> {
+    pub regs: [R; 3],
+    pub error: Option<ExecError>,
+}
+
+/**
+ * Where two runs first part ways. A `None` side had already stopped. If both are `None`, the
+ * runs took the same steps but ended differently.
+ */
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Divergence<R = usize> {
+    pub step: usize,
+    pub left: Option<TraceStep<R>>,
+    pub right: Option<TraceStep<R>>,
+    pub left_end: Ending<R>,
+    pub right_end: Ending<R>,
+}
+
+/**
+ * Runs the program once with each A and finds the first step where they go a different way:
+ * a different instruction, a different output, or one run stopping first. Registers are
+ * expected to differ along the way, so they don't count until the end: runs that take the same
+ * steps still diverge if they stop with different errors or final registers. None if the runs
+ * match.
+ */
+pub fn diff<R: Register>(
+    cpu: &CPU<R>,
+    left_a: R,
+    right_a: R,
+    max_steps: Option<usize>,
+) -> Option<Divergence<R>> {
+    let run = |a: R| {
+        let mut cpu = cpu.clone();
+        cpu.set_reg(0, a);
+        record(&cpu, max_steps)
+    };
+    let (left, right) = (run(left_a), run(right_a));
+    let len = left.steps.len().max(right.steps.len());
+    let step = (0..len)
+        .find(|&i| match (left.steps.get(i), right.steps.get(i)) {
+            (Some(l), Some(r)) => l.pc != r.pc || l.output != r.output,
+            _ => true,
+        })
+        .or_else(|| (left.error != right.error || left.regs != right.regs).then_some(len))?;
+    Some(Divergence {
+        step,
+        left: left.steps.get(step).cloned(),
+        right: right.steps.get(step).cloned(),
+        left_end: Ending {
+            regs: left.regs,
+            error: left.error,
+        },
+        right_end: Ending {
+            regs: right.regs,
+            error: right.error,
+        },
+    })
+}
+
+impl<R: Register> fmt::Display for Divergence<R> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "runs diverge at step {}", self.step)?;
+        let sides = [
+            ("left", &self.left, &self.left_end),
+            ("right", &self.right, &self.right_end),
+        ];
+        for (side, step, end) in sides {
+            match step {
+                Some(s) => {
+                    write!(
+                        f,
+                        "{:>5}: pc {}: {} | A: {:o} B: {:o} C: {:o} -> A: {:o} B: {:o} C: {:o}",
+                        side,
+                        s.pc,
+                        describe(s.opcode, s.operand),
+                        s.before[0],
+                        s.before[1],
+                        s.before[2],
+                        s.after[0],
+                        s.after[1],
+                        s.after[2]
+                    )?;
+                    if let Some(v) = s.output {
+                        write!(f, " | out {}", v)?;
+                    }
+                    writeln!(f)?;
+                }
+                None => {
+                    let reason = match &end.error {
+                        Some(e) => e.to_string(),
+                        None => "halted".to_string(),
+                    };
+                    writeln!(
+                        f,
+                        "{:>5}: stopped, {} | A: {:o} B: {:o} C: {:o}",
+                        side, reason, end.regs[0], end.regs[1], end.regs[2]
+                    )?
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn example() -> CPU {
+        CPU::new([729, 0, 0], vec![0, 1, 5, 4, 3, 0])
+    }
+
+    #[test]
+    fn test_record() {
+        let trace = record(&example(), None);
+        assert_eq!(trace.error, None);
+        assert_eq!(trace.output(), [4, 6, 3, 5, 6, 3, 5, 2, 1, 0]);
+        assert_eq!(trace.steps.len(), 30);
+        assert_eq!(
+            trace.steps[1],
+            TraceStep {
+                step: 1,
+                pc: 2,
+                opcode: 5,
+                operand: 4,
+                before: [364, 0, 0],
+                after: [364, 0, 0],
+                output: Some(4),
+            }
+        );
+
+        let looping = record(&CPU::new([1, 0, 0], vec![3, 0]), None);
+        assert_eq!(looping.error, Some(ExecError::InfiniteLoop { pc: 0 }));
+        let limited = record(&example(), Some(4));
+        assert_eq!(limited.steps.len(), 4);
+        assert_eq!(limited.error, Some(ExecError::StepLimit { limit: 4 }));
+    }
+
+    #[test]
+    fn test_export() {
+        let trace = record(&example(), Some(2));
+        assert_eq!(
+            trace.to_jsonl(),
+            "\
+{\"step\":0,\"pc\":0,\"opcode\":0,\"insn\":\"adv 1\",\"operand\":1,\"before\":[729,0,0],\"after\":[364,0,0],\"output\":null}
+{\"step\":1,\"pc\":2,\"opcode\":5,\"insn\":\"out A\",\"operand\":4,\"before\":[364,0,0],\"after\":[364,0,0],\"output\":4}
+{\"error\":\"still running after 2 instructions\"}
+"
+        );
+        assert_eq!(
+            trace.to_csv(),
+            format!(
+                "{}\n0,0,0,adv 1,1,729,0,0,364,0,0,,\n1,2,5,out A,4,364,0,0,364,0,0,4,\n\
+                 2,,,,,,,,,,,,\"still running after 2 instructions\"\n",
+                CSV_HEADER
+            )
+        );
+    }
+
+    #[test]
+    fn test_diff() {
+        let cpu = example();
+        assert_eq!(diff(&cpu, 729, 729, None), None);
+        // adv 1 drops the lowest bit before anything is output
+        assert_eq!(diff(&cpu, 729, 728, None), None);
+
+        let divergence = diff(&cpu, 729, 730, None).unwrap();
+        assert_eq!(divergence.step, 1);
+        assert_eq!(divergence.left.as_ref().unwrap().output, Some(4));
+        assert_eq!(divergence.right.as_ref().unwrap().output, Some(5));
+        assert!(divergence
+            .to_string()
+            .starts_with("runs diverge at step 1\n"));
+
+        // without any output, a bigger A just loops longer
+        let cpu = CPU::new([0, 0, 0], vec![0, 1, 3, 0]);
+        let divergence = diff(&cpu, 1, 2, None).unwrap();
+        assert_eq!(divergence.step, 2);
+        assert!(divergence.left.is_none());
+        assert_eq!(divergence.right.unwrap().pc, 0);
+    }
+
+    #[test]
+    fn test_diff_endings() {
+        // adv 1, jnz 6, out 7: A = 2 jumps off the end, A = 1 reaches the reserved combo
+        let cpu = CPU::new([0, 0, 0], vec![0, 1, 3, 6, 5, 7]);
+        let divergence = diff(&cpu, 1, 2, None).unwrap();
+        assert_eq!(divergence.step, 2);
+        assert!(divergence.left.is_none() && divergence.right.is_none());
+        assert_eq!(
+            divergence.left_end.error,
+            Some(ExecError::InvalidCombo { pc: 4 })
+        );
+        assert_eq!(divergence.right_end.error, None);
+        assert_eq!(
+            divergence.to_string(),
+            "runs diverge at step 2\n \
+             left: stopped, combo operand 7 is reserved (instruction at 4) | A: 0 B: 0 C: 0\n\
+             right: stopped, halted | A: 1 B: 0 C: 0\n"
+        );
+
+        // A = 1 halts after two steps, A = 2 would loop round again but hits the limit
+        let cpu = CPU::new([0, 0, 0], vec![0, 1, 3, 0]);
+        let divergence = diff(&cpu, 1, 2, Some(2)).unwrap();
+        assert_eq!(divergence.step, 2);
+        assert_eq!(divergence.left_end.error, None);
+        assert_eq!(
+            divergence.right_end.error,
+            Some(ExecError::StepLimit { limit: 2 })
+        );
+
+        // bst A: same path, different B at the end
+        let cpu = CPU::new([0, 0, 0], vec![2, 4]);
+        let divergence = diff(&cpu, 1, 2, None).unwrap();
+        assert_eq!(divergence.step, 1);
+        assert_eq!(divergence.left_end.regs, [1, 1, 0]);
+        assert_eq!(divergence.right_end.regs, [2, 2, 0]);
+    }
+}
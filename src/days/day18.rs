@@ -0,0 +1,301 @@
+use std::collections::{HashSet, VecDeque};
+
+use crate::error::Error;
+use crate::solution::{Solution, Variant};
+use crate::union_find::UnionFind;
+use crate::*;
+
+pub struct Day18;
+
+impl Solution for Day18 {
+    type Input = Vec<(usize, usize)>;
+
+    fn day(&self) -> i32 {
+        18
+    }
+
+    fn title(&self) -> &'static str {
+        "RAM Run"
+    }
+
+    fn parse(&self, input: &str) -> Result<Self::Input, Error> {
+        Ok(parse(input)?)
+    }
+
+    fn part1(&self, input: &Self::Input) -> String {
+        part1(input)
+    }
+
+    fn part2(&self, input: &Self::Input) -> String {
+        part2(input)
+    }
+
+    fn variants(&self) -> Vec<Variant<Self::Input>> {
+        vec![
+            Variant::new("bisect", |bytes| part2_bisect(bytes)),
+            Variant::new("naive", |bytes| part2_naive(bytes)),
+        ]
+    }
+}
+
+fn parse(input: &str) -> Result<Vec<(usize, usize)>, ParseError> {
+    Ok(parse_int_lines::<usize, 2>(input)?
+        .into_iter()
+        .map(|[x, y]| (y, x))
+        .collect())
+}
+
+fn part1(bytes: &[(usize, usize)]) -> String {
+    part1_var_size(bytes, 71, 1024).to_string()
+}
+
+fn part2(bytes: &[(usize, usize)]) -> String {
+    blocking_answer(part2_reverse(bytes, 71))
+}
+
+fn part2_bisect(bytes: &[(usize, usize)]) -> String {
+    blocking_answer(part2_bisect_var_size(bytes, 71))
+}
+
+/**
+ * The original: drop bytes one at a time past the first kilobyte, re-running the BFS each time.
+ */
+fn part2_naive(bytes: &[(usize, usize)]) -> String {
+    blocking_answer(part2_var_size(bytes, 71, 1024))
+}
+
+/**
+ * The byte as `x,y` like the puzzle wants, or a note that the exit never gets cut off.
+ */
+fn blocking_answer(byte: Option<(usize, usize)>) -> String {
+    match byte {
+        Some((y, x)) => format!("{},{}", x, y),
+        None => "no blocking byte".to_string(),
+    }
+}
+
+/**
+ * Run time backwards: start with every byte fallen, then lift them off last-first, merging each
+ * freed cell with its open neighbours. The first byte whose removal joins the corners is the one
+ * that cut them apart. One union-find pass, so roughly linear in grid cells plus bytes.
+ */
+fn part2_reverse(bytes: &[(usize, usize)], grid_size: usize) -> Option<(usize, usize)> {
+    // only the first byte to land on a cell blocks it
+    let mut fallen_at: Grid<Option<usize>> = Grid::new(grid_size, grid_size, None);
+    for (i, &byte) in bytes.iter().enumerate() {
+        fallen_at[byte].get_or_insert(i);
+    }
+
+    let id = |(i, j): (usize, usize)| i * grid_size + j;
+    let start = id((0, 0));
+    let end = id((grid_size - 1, grid_size - 1));
+    let mut open = fallen_at.map(|fallen| fallen.is_none());
+    let mut sets = UnionFind::new(grid_size * grid_size);
+    for pos in open.positions() {
+        if open[pos] {
+            for neighbour in open.neighbours4(pos) {
+                if open[neighbour] {
+                    sets.union(id(pos), id(neighbour));
+                }
+            }
+        }
+    }
+    if sets.connected(start, end) {
+        return None;
+    }
+
+    for (i, &byte) in bytes.iter().enumerate().rev() {
+        if fallen_at[byte] != Some(i) {
+            continue;
+        }
+        open[byte] = true;
+        for neighbour in open.neighbours4(byte) {
+            if open[neighbour] {
+                sets.union(id(byte), id(neighbour));
+            }
+        }
+        if sets.connected(start, end) {
+            return Some(byte);
+        }
+    }
+    None
+}
+
+/**
+ * Binary search on how many bytes have fallen, with one BFS per probe.
+ */
+fn part2_bisect_var_size(bytes: &[(usize, usize)], grid_size: usize) -> Option<(usize, usize)> {
+    let blocked_after = |num_bytes: usize| {
+        let mut grid = Grid::new(grid_size, grid_size, true);
+        fill_bytes(&mut grid, bytes, num_bytes);
+        bfs_len(&grid) == 0
+    };
+
+    if !blocked_after(bytes.len()) {
+        return None;
+    }
+    // invariant: the path is open after `lo` bytes and blocked after `hi`
+    let (mut lo, mut hi) = (0, bytes.len());
+    while hi - lo > 1 {
+        let mid = (lo + hi) / 2;
+        if blocked_after(mid) {
+            hi = mid;
+        } else {
+            lo = mid;
+        }
+    }
+    Some(bytes[hi - 1])
+}
+
+fn part2_var_size(
+    bytes: &[(usize, usize)],
+    grid_size: usize,
+    num_bytes: usize,
+) -> Option<(usize, usize)> {
+    let mut grid = Grid::new(grid_size, grid_size, true);
+
+    fill_bytes(&mut grid, bytes, num_bytes);
+    for i in num_bytes..bytes.len() {
+        grid[bytes[i]] = false;
+        let len = bfs_len(&grid);
+        if len == 0 {
+            return Some(bytes[i]);
+        }
+    }
+    None
+}
+
+fn part1_var_size(bytes: &[(usize, usize)], grid_size: usize, num_bytes: usize) -> String {
+    let mut grid = Grid::new(grid_size, grid_size, true);
+    fill_bytes(&mut grid, bytes, num_bytes);
+    bfs_len(&grid).to_string()
+}
+
+/**
+* return how long it takes to get from top left to bottom right
+*/
+fn bfs_len(grid: &Grid<bool>) -> usize {
+    let end = (grid.height() - 1, grid.width() - 1);
+    let mut explored = HashSet::new();
+    let mut to_explore = VecDeque::new();
+    to_explore.push_back(((0, 0), 0));
+
+    while !to_explore.is_empty() {
+        let (coord, dist) = to_explore.pop_front().unwrap();
+        if explored.contains(&coord) {
+            continue;
+        }
+        explored.insert(coord);
+        if coord == end {
+            return dist;
+        }
+        for new_explore in grid.neighbours4(coord) {
+            if grid[new_explore] {
+                to_explore.push_back((new_explore, dist + 1));
+            }
+        }
+    }
+    0
+}
+
+fn fill_bytes(grid: &mut Grid<bool>, bytes: &[(usize, usize)], num: usize) {
+    for &byte in &bytes[..num] {
+        grid[byte] = false;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generate::falling_bytes;
+    use crate::pathfind::dijkstra_to;
+
+    static TEST_INPUT: &str = "5,4
+4,2
+4,5
+3,0
+2,1
+6,3
+2,4
+1,5
+0,6
+3,3
+2,6
+5,1
+1,2
+5,5
+2,5
+6,5
+1,4
+0,4
+6,4
+1,1
+6,1
+1,0
+0,5
+1,6
+2,0";
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(part1_var_size(&parse(TEST_INPUT).unwrap(), 7, 12), "22");
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(
+            part2_var_size(&parse(TEST_INPUT).unwrap(), 7, 12),
+            Some((1, 6))
+        );
+    }
+
+    #[test]
+    fn test_part2_reverse() {
+        assert_eq!(part2_reverse(&parse(TEST_INPUT).unwrap(), 7), Some((1, 6)));
+    }
+
+    #[test]
+    fn test_part2_bisect() {
+        assert_eq!(
+            blocking_answer(part2_bisect_var_size(&parse(TEST_INPUT).unwrap(), 7)),
+            "6,1"
+        );
+    }
+
+    #[test]
+    fn test_part2_never_blocked() {
+        let bytes = parse(TEST_INPUT).unwrap();
+        assert_eq!(part2_reverse(&bytes[..12], 7), None);
+        assert_eq!(part2_bisect_var_size(&bytes[..12], 7), None);
+        assert_eq!(part2_var_size(&bytes[..12], 7, 12), None);
+        assert_eq!(blocking_answer(None), "no blocking byte");
+    }
+
+    #[test]
+    fn test_generated() {
+        // three times the width of the real memory space
+        let size = 213;
+        for seed in 0..3 {
+            let bytes = parse(&falling_bytes(seed, size, size * size)).unwrap();
+            assert_eq!(
+                part2_reverse(&bytes, size),
+                part2_bisect_var_size(&bytes, size)
+            );
+
+            let mut grid = Grid::new(size, size, true);
+            fill_bytes(&mut grid, &bytes, size * size / 4);
+            let end = (size - 1, size - 1);
+            let paths = dijkstra_to(
+                [(0, 0)],
+                |&pos| {
+                    grid.neighbours4(pos)
+                        .filter(|&n| grid[n])
+                        .map(|n| (n, 1))
+                        .collect::<Vec<_>>()
+                },
+                |&pos| pos == end,
+            );
+            assert_eq!(bfs_len(&grid), paths.goal_distance().unwrap_or(0));
+        }
+    }
+}
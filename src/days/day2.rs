@@ -1,16 +1,40 @@
 use std::cmp;
 
-use aoc24::*;
+use crate::error::Error;
+use crate::solution::Solution;
+use crate::*;
 
-fn main() {
-    let binding = read_input(2);
-    let input = binding.as_str();
-    println!("Part 1: {}", part1(&input));
-    println!("Part 2: {}", part2(&input))
+pub struct Day2;
+
+impl Solution for Day2 {
+    type Input = Vec<Vec<i32>>;
+
+    fn day(&self) -> i32 {
+        2
+    }
+
+    fn title(&self) -> &'static str {
+        "Red-Nosed Reports"
+    }
+
+    fn parse(&self, input: &str) -> Result<Self::Input, Error> {
+        Ok(parse(input))
+    }
+
+    fn part1(&self, input: &Self::Input) -> String {
+        part1(input)
+    }
+
+    fn part2(&self, input: &Self::Input) -> String {
+        part2(input)
+    }
 }
 
-fn part1(input: &str) -> String {
-    let grid = parse_row_major::<i32>(input, " ");
+fn parse(input: &str) -> Vec<Vec<i32>> {
+    parse_row_major::<i32>(input, " ")
+}
+
+fn part1(grid: &Vec<Vec<i32>>) -> String {
     let mut num_safe = 0;
     for row in grid {
         let increasing = row[1] >= row[0];
@@ -21,7 +45,7 @@ fn part1(input: &str) -> String {
                 break;
             }
             let difference = row[i].abs_diff(row[i - 1]);
-            if difference < 1 || difference > 3 {
+            if !(1..=3).contains(&difference) {
                 safe = false;
                 break;
             }
@@ -31,9 +55,13 @@ fn part1(input: &str) -> String {
         };
     }
 
-    return num_safe.to_string();
+    num_safe.to_string()
 }
 
+#[allow(
+    dead_code,
+    reason = "the first attempt at part 2, kept to show what went wrong"
+)]
 fn part2_wrong(input: &str) -> String {
     let grid = parse_row_major::<i32>(input, " ");
     let mut num_safe = 0;
@@ -43,11 +71,10 @@ fn part2_wrong(input: &str) -> String {
         }
     }
 
-    return num_safe.to_string();
+    num_safe.to_string()
 }
 
-fn part2(input: &str) -> String {
-    let grid = parse_row_major::<i32>(input, " ");
+fn part2(grid: &Vec<Vec<i32>>) -> String {
     let mut num_safe = 0;
     for row in grid {
         for i in 0..row.len() {
@@ -59,13 +86,14 @@ fn part2(input: &str) -> String {
             }
         }
     }
-    return num_safe.to_string();
+    num_safe.to_string()
 }
 
 /**
  * @return (whether the sequence is valid, whether the sequence is increasing or not)
  */
-fn check_increasing_dir(row: &Vec<i32>) -> (bool, bool) {
+#[allow(dead_code, reason = "only used by part2_wrong")]
+fn check_increasing_dir(row: &[i32]) -> (bool, bool) {
     let mut inc_count = (0, 0);
     for i in 1..row.len() {
         if row[i] > row[i - 1] {
@@ -79,7 +107,7 @@ fn check_increasing_dir(row: &Vec<i32>) -> (bool, bool) {
         return (false, false);
     }
     let increasing = inc_count.0 > inc_count.1;
-    return (true, increasing);
+    (true, increasing)
 }
 
 fn check_valid_pair(a: i32, b: i32, increasing: bool) -> bool {
@@ -87,24 +115,25 @@ fn check_valid_pair(a: i32, b: i32, increasing: bool) -> bool {
         return false;
     }
     let difference = a.abs_diff(b);
-    if difference < 1 || difference > 3 {
+    if !(1..=3).contains(&difference) {
         return false;
     }
-    return true;
+    true
 }
 
-fn check_row_valid(row: &Vec<i32>) -> bool {
+fn check_row_valid(row: &[i32]) -> bool {
     let increasing = row[1] > row[0];
     for i in 1..row.len() {
         if !check_valid_pair(row[i], row[i - 1], increasing) {
             return false;
         }
     }
-    return true;
+    true
 }
 
-fn check_with_dampener(row: &Vec<i32>) -> bool {
-    let (dir_valid, increasing) = check_increasing_dir(&row);
+#[allow(dead_code, reason = "only used by part2_wrong")]
+fn check_with_dampener(row: &[i32]) -> bool {
+    let (dir_valid, increasing) = check_increasing_dir(row);
     if !dir_valid {
         return false;
     }
@@ -132,7 +161,7 @@ fn check_with_dampener(row: &Vec<i32>) -> bool {
         }
         i += 1;
     }
-    return true;
+    true
 }
 
 #[cfg(test)]
@@ -142,30 +171,30 @@ mod tests {
     #[test]
     fn test_part1() {
         let test_input = "7 6 4 2 1\n1 2 7 8 9\n9 7 6 2 1\n1 3 2 4 5\n8 6 4 4 1\n1 3 6 7 9";
-        assert_eq!(part1(test_input), "2");
+        assert_eq!(part1(&parse(test_input)), "2");
     }
 
     #[test]
     fn tst_part2() {
         let test_input = "7 6 4 2 1\n1 2 7 8 9\n9 7 6 2 1\n1 3 2 4 5\n8 6 4 4 1\n1 3 6 7 9";
-        assert_eq!(part2(test_input), "4");
+        assert_eq!(part2(&parse(test_input)), "4");
     }
 
     #[test]
     fn tst_part2_3() {
         let test_input = "1 2 7 8 9";
-        assert_eq!(part2(test_input), "0");
+        assert_eq!(part2(&parse(test_input)), "0");
     }
 
     #[test]
     fn tst_part2_1323() {
         let test_input2 = "1 3 2 3";
-        assert_eq!(part2(test_input2), "1")
+        assert_eq!(part2(&parse(test_input2)), "1")
     }
 
     #[test]
     fn tst_part2_1023() {
         let test_input2 = "1 0 2 3";
-        assert_eq!(part2(test_input2), "1")
+        assert_eq!(part2(&parse(test_input2)), "1")
     }
 }
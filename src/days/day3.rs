@@ -1,12 +1,32 @@
 use regex::Regex;
 
-use aoc24::*;
+use crate::error::Error;
+use crate::solution::Solution;
 
-fn main() {
-    let binding = read_input(3);
-    let input = binding.as_str();
-    println!("Part 1: {}", part1(&input));
-    println!("Part 2: {}", part2(&input))
+pub struct Day3;
+
+impl Solution for Day3 {
+    type Input = String;
+
+    fn day(&self) -> i32 {
+        3
+    }
+
+    fn title(&self) -> &'static str {
+        "Mull It Over"
+    }
+
+    fn parse(&self, input: &str) -> Result<Self::Input, Error> {
+        Ok(input.to_string())
+    }
+
+    fn part1(&self, input: &Self::Input) -> String {
+        part1(input)
+    }
+
+    fn part2(&self, input: &Self::Input) -> String {
+        part2(input)
+    }
 }
 
 fn part1(input: &str) -> String {
@@ -20,7 +40,7 @@ fn part1(input: &str) -> String {
         })
         .collect();
     let acc = muls.iter().fold(0, |acc, tup| acc + (tup.0 * tup.1));
-    return format!("{:#?}", acc);
+    format!("{:#?}", acc)
 }
 
 fn part2(input: &str) -> String {
@@ -44,7 +64,7 @@ fn part2(input: &str) -> String {
         }
     }
 
-    return format!("{:#?}", acc);
+    format!("{:#?}", acc)
 }
 
 #[cfg(test)]
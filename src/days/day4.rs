@@ -0,0 +1,115 @@
+use std::collections::HashSet;
+
+use crate::error::Error;
+use crate::grid::DELTAS_8;
+use crate::solution::Solution;
+use crate::*;
+
+pub struct Day4;
+
+impl Solution for Day4 {
+    type Input = Grid<char>;
+
+    fn day(&self) -> i32 {
+        4
+    }
+
+    fn title(&self) -> &'static str {
+        "Ceres Search"
+    }
+
+    fn parse(&self, input: &str) -> Result<Self::Input, Error> {
+        Ok(Grid::parse(input))
+    }
+
+    fn part1(&self, input: &Self::Input) -> String {
+        part1(input)
+    }
+
+    fn part2(&self, input: &Self::Input) -> String {
+        part2(input)
+    }
+}
+
+fn part1(grid: &Grid<char>) -> String {
+    let mut xmascount = 0;
+
+    for pos in grid.positions() {
+        for delta in DELTAS_8 {
+            if check_word(grid, pos, delta, "XMAS") {
+                xmascount += 1
+            }
+        }
+    }
+
+    xmascount.to_string()
+}
+
+fn part2(grid: &Grid<char>) -> String {
+    let deltas: Vec<(i32, i32)> = vec![(1, 1), (-1, 1), (-1, -1), (1, -1)];
+
+    let mut xmascount = 0;
+    let mut mas_locs: HashSet<(usize, usize)> = HashSet::new();
+
+    for pos in grid.positions() {
+        for delta in &deltas {
+            if check_word(grid, pos, *delta, "MAS") {
+                // this is the location of the common A in a X-MAS formation
+                let a_loc = grid.offset(pos, *delta).unwrap();
+                if !mas_locs.insert(a_loc) {
+                    xmascount += 1;
+                }
+            }
+        }
+    }
+
+    xmascount.to_string()
+}
+
+fn check_word(grid: &Grid<char>, coords: (usize, usize), delta: (i32, i32), word: &str) -> bool {
+    let mut cur_coords = Some(coords);
+    for letter in word.chars() {
+        match cur_coords {
+            Some(loc) if grid[loc] == letter => cur_coords = grid.offset(loc, delta),
+            _ => return false,
+        }
+    }
+    true
+}
+
+//fn part2(input: &str) -> String {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part1() {
+        let test_input = "MMMSXXMASM
+MSAMXMSMSA
+AMXSXMAAMM
+MSAMASMSMX
+XMASAMXAMM
+XXAMMXXAMA
+SMSMSASXSS
+SAXAMASAAA
+MAMMMXMMMM
+MXMXAXMASX";
+        assert_eq!(part1(&Grid::parse(test_input)), "18");
+    }
+
+    #[test]
+    fn test_part2() {
+        let test_input = "MMMSXXMASM
+MSAMXMSMSA
+AMXSXMAAMM
+MSAMASMSMX
+XMASAMXAMM
+XXAMMXXAMA
+SMSMSASXSS
+SAXAMASAAA
+MAMMMXMMMM
+MXMXAXMASX";
+        assert_eq!(part2(&Grid::parse(test_input)), "9");
+    }
+}
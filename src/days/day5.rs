@@ -0,0 +1,174 @@
+use crate::error::Error;
+use crate::graph::DiGraph;
+use crate::solution::Solution;
+use crate::*;
+
+pub struct Day5;
+
+impl Solution for Day5 {
+    type Input = (Vec<Vec<usize>>, Vec<Vec<usize>>);
+
+    fn day(&self) -> i32 {
+        5
+    }
+
+    fn title(&self) -> &'static str {
+        "Print Queue"
+    }
+
+    fn parse(&self, input: &str) -> Result<Self::Input, Error> {
+        Ok(parse(input))
+    }
+
+    fn part1(&self, input: &Self::Input) -> String {
+        part1(input)
+    }
+
+    fn part2(&self, input: &Self::Input) -> String {
+        part2(input)
+    }
+}
+
+/**
+ * Returns (rules, updates)
+ */
+fn parse(input: &str) -> (Vec<Vec<usize>>, Vec<Vec<usize>>) {
+    let sections = split_sections(input);
+    let rules = parse_row_major::<usize>(sections[0], "|");
+    let updates = parse_row_major::<usize>(sections[1], ",");
+    (rules, updates)
+}
+
+fn part1(input: &(Vec<Vec<usize>>, Vec<Vec<usize>>)) -> String {
+    let (rules, updates) = input;
+
+    let mut sum = 0;
+    for update in updates {
+        if check_all_rules(rules, update) {
+            sum += middle_page(update);
+        }
+    }
+
+    sum.to_string()
+}
+
+fn part2(input: &(Vec<Vec<usize>>, Vec<Vec<usize>>)) -> String {
+    // topo sort rules
+    let (rules, updates) = input;
+    let mut updates = updates.clone();
+    // Rules may have cycles...
+    // filter out correctly-sorted updates
+    updates.retain(|v| !check_all_rules(rules, v));
+    let mut sum = 0;
+    // grab correct ordering of rules from topo-sorted list
+    for update in &updates {
+        let relevant_rules = get_relevant_rules(rules, update);
+        let mut sorted_update = topo_sort_pages(&relevant_rules);
+        sorted_update.retain(|x| update.contains(x));
+
+        // return middle
+        sum += middle_page(&sorted_update);
+    }
+
+    sum.to_string()
+}
+
+fn get_relevant_rules(all_rules: &[Vec<usize>], update: &[usize]) -> Vec<Vec<usize>> {
+    all_rules
+        .iter()
+        .filter(|v| update.contains(&v[0]) && update.contains(&v[1]))
+        .cloned()
+        .collect()
+}
+
+/**
+ * The full rule set has cycles, but the rules relevant to a single update never do.
+ */
+fn topo_sort_pages(rules: &[Vec<usize>]) -> Vec<usize> {
+    let graph = DiGraph::from_edges(rules.iter().map(|rule| (rule[0], rule[1])));
+    graph
+        .topo_sort()
+        .unwrap_or_else(|cycle| panic!("page rules can't be ordered, {}", cycle))
+}
+
+fn check_all_rules(rules: &Vec<Vec<usize>>, update: &[usize]) -> bool {
+    for rule in rules {
+        if !check_rule(rule, update) {
+            return false;
+        }
+    }
+    true
+}
+
+fn check_rule(rule: &[usize], update: &[usize]) -> bool {
+    let index1 = update.iter().position(|&n| n == rule[0]);
+    let index2 = update.iter().position(|&n| n == rule[1]);
+    if let (Some(index1), Some(index2)) = (index1, index2) {
+        return index1 < index2;
+    }
+    true
+}
+
+fn middle_page(update: &[usize]) -> usize {
+    if update.is_empty() {
+        return 0;
+    }
+    update[update.len() / 2]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    static TEST_INPUT: &str = "47|53
+97|13
+97|61
+97|47
+75|29
+61|13
+75|53
+29|13
+97|29
+53|29
+61|53
+97|53
+61|29
+47|13
+75|47
+97|75
+47|61
+75|61
+47|29
+75|13
+53|13
+
+75,47,61,53,29
+97,61,53,29,13
+75,29,13
+75,97,47,61,53
+61,13,29
+97,13,75,29,47";
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(part1(&parse(TEST_INPUT)), "143");
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(part2(&parse(TEST_INPUT)), "123");
+    }
+
+    #[test]
+    fn test_topo() {
+        let rules = parse_row_major::<usize>(split_sections(TEST_INPUT)[0], "|");
+        let topo = topo_sort_pages(&rules);
+        assert!(check_all_rules(&rules, &topo));
+    }
+
+    #[test]
+    fn test_topo_simple() {
+        let input_simple = "1|2\n2|4\n3|4\n\n1,4,2";
+        assert_eq!(part2(&parse(input_simple)), "2");
+    }
+}
@@ -0,0 +1,128 @@
+use std::collections::HashSet;
+
+use crate::error::Error;
+use crate::solution::Solution;
+use crate::*;
+
+pub struct Day6;
+
+impl Solution for Day6 {
+    type Input = Grid<char>;
+
+    fn day(&self) -> i32 {
+        6
+    }
+
+    fn title(&self) -> &'static str {
+        "Guard Gallivant"
+    }
+
+    fn parse(&self, input: &str) -> Result<Self::Input, Error> {
+        Ok(Grid::parse(input))
+    }
+
+    fn part1(&self, input: &Self::Input) -> String {
+        part1(input)
+    }
+
+    fn part2(&self, input: &Self::Input) -> String {
+        part2(input)
+    }
+}
+
+fn part1(grid: &Grid<char>) -> String {
+    orig_path(grid).len().to_string()
+}
+
+fn orig_path(grid: &Grid<char>) -> HashSet<(usize, usize)> {
+    let mut loc = find_start(grid);
+    let mut visited: HashSet<(usize, usize)> = HashSet::new();
+    let mut dir = Direction::Up;
+
+    loop {
+        visited.insert(loc);
+        let Some(newloc) = grid.offset(loc, dir) else {
+            break;
+        };
+        if grid[newloc] == '#' {
+            dir = dir.turn_right();
+        } else {
+            loc = newloc;
+        }
+    }
+
+    visited
+}
+
+/**
+ * Exhaustively search through all obstruction locations
+ * Check to see if guard ends up in same location with same orientation
+ * Keep track of visited locations with 3-tuple now, third representing orientation.
+ */
+fn part2(grid: &Grid<char>) -> String {
+    let start = find_start(grid);
+    let mut count = 0;
+
+    for loc in orig_path(grid) {
+        if loop_check(start, loc, grid) {
+            count += 1;
+        }
+    }
+    count.to_string()
+}
+
+/**
+* returns true if there's a loop
+*/
+fn loop_check(start: (usize, usize), obstacle: (usize, usize), grid: &Grid<char>) -> bool {
+    if grid[obstacle] == '#' || grid[obstacle] == '^' {
+        return false;
+    }
+    let mut loc = start;
+    let mut visited: HashSet<(usize, usize, Direction)> = HashSet::new();
+    let mut dir = Direction::Up;
+
+    loop {
+        if !visited.insert((loc.0, loc.1, dir)) {
+            return true;
+        }
+        let Some(newloc) = grid.offset(loc, dir) else {
+            return false;
+        };
+        if grid[newloc] == '#' || newloc == obstacle {
+            dir = dir.turn_right();
+        } else {
+            loc = newloc;
+        }
+    }
+}
+
+fn find_start(grid: &Grid<char>) -> (usize, usize) {
+    grid.find(&'^').unwrap_or((0, 0))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    static TEST_INPUT: &str = "....#.....
+.........#
+..........
+..#.......
+.......#..
+..........
+.#..^.....
+........#.
+#.........
+......#...";
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(part1(&Grid::parse(TEST_INPUT)), "41");
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(part2(&Grid::parse(TEST_INPUT)), "6");
+    }
+}
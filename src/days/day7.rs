@@ -1,18 +1,47 @@
-use aoc24::*;
+use crate::error::Error;
+use crate::solution::Solution;
+use crate::*;
 
-fn main() {
-    let binding = read_input(7);
-    let input = binding.as_str();
-    println!("Part 1: {}", part1(&input));
-    println!("Part 2: {}", part2(&input));
+pub struct Day7;
+
+impl Solution for Day7 {
+    type Input = (Vec<usize>, Vec<Vec<usize>>);
+
+    fn day(&self) -> i32 {
+        7
+    }
+
+    fn title(&self) -> &'static str {
+        "Bridge Repair"
+    }
+
+    fn parse(&self, input: &str) -> Result<Self::Input, Error> {
+        Ok(parse(input))
+    }
+
+    fn part1(&self, input: &Self::Input) -> String {
+        part1(input)
+    }
+
+    fn part2(&self, input: &Self::Input) -> String {
+        part2(input)
+    }
 }
 
-fn part1(input: &str) -> String {
+/**
+ * Returns (targets, numbers for each target)
+ */
+fn parse(input: &str) -> (Vec<usize>, Vec<Vec<usize>>) {
     let targets = parse_row_major::<usize>(input, ": ");
     let mut numbers = parse_row_major::<usize>(input, " ");
 
     let targets = unbox_first_element(&targets);
     remove_first_element(&mut numbers);
+    (targets, numbers)
+}
+
+fn part1(input: &(Vec<usize>, Vec<Vec<usize>>)) -> String {
+    let (targets, numbers) = input;
 
     let mut sum: u64 = 0;
     for i in 0..targets.len() {
@@ -21,15 +50,11 @@ fn part1(input: &str) -> String {
         }
     }
 
-    return sum.to_string();
+    sum.to_string()
 }
 
-fn part2(input: &str) -> String {
-    let targets = parse_row_major::<usize>(input, ": ");
-    let mut numbers = parse_row_major::<usize>(input, " ");
-
-    let targets = unbox_first_element(&targets);
-    remove_first_element(&mut numbers);
+fn part2(input: &(Vec<usize>, Vec<Vec<usize>>)) -> String {
+    let (targets, numbers) = input;
 
     let mut sum: u64 = 0;
     for i in 0..targets.len() {
@@ -38,7 +63,7 @@ fn part2(input: &str) -> String {
         }
     }
 
-    return sum.to_string();
+    sum.to_string()
 }
 
 fn check_compliant_concat(
@@ -72,7 +97,7 @@ fn check_compliant_concat(
             || check_compliant_concat(target, numbers, index + 1, cur_value + numbers[index]);
     }
 
-    return result;
+    result
 }
 
 fn check_compliant(target: usize, numbers: &Vec<usize>, index: usize, cur_value: usize) -> bool {
@@ -88,7 +113,7 @@ fn check_compliant(target: usize, numbers: &Vec<usize>, index: usize, cur_value:
         result = result || check_compliant(target, numbers, index + 1, cur_value + numbers[index]);
     }
 
-    return result;
+    result
 }
 
 fn num_concat(a: usize, b: usize) -> usize {
@@ -97,7 +122,7 @@ fn num_concat(a: usize, b: usize) -> usize {
     for _ in 0..num_digits {
         result *= 10;
     }
-    return result + b;
+    result + b
 }
 
 fn num_digits(x: usize) -> usize {
@@ -111,15 +136,15 @@ fn num_digits(x: usize) -> usize {
         digits += 1;
         temp /= 10;
     }
-    return digits;
+    digits
 }
 
-fn unbox_first_element<T: std::clone::Clone>(arr: &Vec<Vec<T>>) -> Vec<T> {
+fn unbox_first_element<T: std::clone::Clone>(arr: &[Vec<T>]) -> Vec<T> {
     let ret: Vec<T> = arr.iter().map(|row| row[0].clone()).collect();
-    return ret;
+    ret
 }
 
-fn remove_first_element<T>(arr: &mut Vec<Vec<T>>) -> () {
+fn remove_first_element<T>(arr: &mut Vec<Vec<T>>) {
     for row in arr {
         row.remove(0);
     }
@@ -141,17 +166,17 @@ mod tests {
 
     #[test]
     fn test_part1() {
-        assert_eq!(part1(TEST_INPUT), "3749");
+        assert_eq!(part1(&parse(TEST_INPUT)), "3749");
     }
 
     #[test]
     fn test_part2() {
-        assert_eq!(part2(TEST_INPUT), "11387");
+        assert_eq!(part2(&parse(TEST_INPUT)), "11387");
     }
 
     #[test]
     fn test_simple_part2() {
-        assert_eq!(part2("12345: 123 45"), "12345");
+        assert_eq!(part2(&parse("12345: 123 45")), "12345");
     }
 
     #[test]
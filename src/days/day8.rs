@@ -0,0 +1,161 @@
+use std::{
+    cmp::{max, min},
+    collections::{HashMap, HashSet},
+    mem::swap,
+};
+
+use crate::error::Error;
+use crate::solution::Solution;
+use crate::*;
+
+pub struct Day8;
+
+impl Solution for Day8 {
+    type Input = (Grid<char>, HashMap<char, Vec<Point>>);
+
+    fn day(&self) -> i32 {
+        8
+    }
+
+    fn title(&self) -> &'static str {
+        "Resonant Collinearity"
+    }
+
+    fn parse(&self, input: &str) -> Result<Self::Input, Error> {
+        Ok(get_antennae_list(input))
+    }
+
+    fn part1(&self, input: &Self::Input) -> String {
+        part1(input)
+    }
+
+    fn part2(&self, input: &Self::Input) -> String {
+        part2(input)
+    }
+}
+
+fn part1(input: &(Grid<char>, HashMap<char, Vec<Point>>)) -> String {
+    let (grid, antennae) = input;
+
+    let mut antinodes: HashSet<Point> = HashSet::new();
+
+    for matched in antennae.values() {
+        add_uniq_nodes(matched, &mut antinodes, grid);
+    }
+
+    antinodes.len().to_string()
+}
+
+fn part2(input: &(Grid<char>, HashMap<char, Vec<Point>>)) -> String {
+    let (grid, antennae) = input;
+
+    let mut antinodes: HashSet<Point> = HashSet::new();
+
+    for matched in antennae.values() {
+        add_lattice_points(matched, &mut antinodes, grid);
+    }
+
+    antinodes.len().to_string()
+}
+
+fn get_antennae_list(input: &str) -> (Grid<char>, HashMap<char, Vec<Point>>) {
+    let grid = Grid::parse(input);
+    let mut antennae: HashMap<char, Vec<Point>> = HashMap::new();
+
+    for ((i, j), &char) in grid.iter() {
+        if char != '.' {
+            antennae.entry(char).or_default().push(Point::from((i, j)));
+        }
+    }
+    (grid, antennae)
+}
+
+// TODO: reduce diff coords down to irreducible form: divide by gcf
+fn irreducible_coords(coord: Point) -> Point {
+    let gcf = gcf(coord.row, coord.col);
+    Point::new(coord.row / gcf, coord.col / gcf)
+}
+
+fn gcf(a: i32, b: i32) -> i32 {
+    let mut greater = max(a, b);
+    let mut lesser = min(a, b);
+    if lesser == 0 {
+        return greater;
+    }
+
+    loop {
+        greater %= lesser;
+        if greater == 0 {
+            return lesser;
+        }
+
+        swap(&mut greater, &mut lesser);
+    }
+}
+
+/**
+* Adds every grid point lying on the line through each pair of antennae
+*/
+fn add_lattice_points(antennae: &[Point], locs: &mut HashSet<Point>, grid: &Grid<char>) {
+    for i in 0..antennae.len() {
+        for j in i + 1..antennae.len() {
+            let diff = irreducible_coords(antennae[j] - antennae[i]);
+
+            let mut cur_coord = antennae[i];
+            while grid.locate(cur_coord).is_some() {
+                locs.insert(cur_coord);
+                cur_coord += diff;
+            }
+            cur_coord = antennae[i];
+            while grid.locate(cur_coord).is_some() {
+                locs.insert(cur_coord);
+                cur_coord -= diff;
+            }
+        }
+    }
+}
+
+fn add_uniq_nodes(antennae: &[Point], locs: &mut HashSet<Point>, grid: &Grid<char>) {
+    for i in 0..antennae.len() {
+        for j in i + 1..antennae.len() {
+            let diff = antennae[j] - antennae[i];
+            let node1 = antennae[j] + diff;
+            let node2 = antennae[i] - diff;
+
+            if grid.locate(node1).is_some() {
+                locs.insert(node1);
+            }
+            if grid.locate(node2).is_some() {
+                locs.insert(node2);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    static TEST_INPUT: &str = "............
+........0...
+.....0......
+.......0....
+....0.......
+......A.....
+............
+............
+........A...
+.........A..
+............
+............";
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(part1(&get_antennae_list(TEST_INPUT)), "14");
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(part2(&get_antennae_list(TEST_INPUT)), "34");
+    }
+}
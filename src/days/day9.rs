@@ -1,20 +1,39 @@
-use std::mem;
+use crate::error::Error;
+use crate::solution::Solution;
+use crate::*;
 
-use aoc24::*;
+pub struct Day9;
 
-fn main() {
-    let binding = read_input(9);
-    let input = binding.as_str();
-    println!("Part 1: {}", part1(&input));
-    //println!("Part 2: {}", part2("2333133121414131402"));
-    println!("Part 2: {}", part2(&input));
+impl Solution for Day9 {
+    type Input = Vec<usize>;
+
+    fn day(&self) -> i32 {
+        9
+    }
+
+    fn title(&self) -> &'static str {
+        "Disk Fragmenter"
+    }
+
+    fn parse(&self, input: &str) -> Result<Self::Input, Error> {
+        Ok(parse(input))
+    }
+
+    fn part1(&self, input: &Self::Input) -> String {
+        part1(input)
+    }
+
+    fn part2(&self, input: &Self::Input) -> String {
+        part2(input)
+    }
 }
 
-// TODO: Make soln more efficient/idiomatic
-fn part1(input: &str) -> String {
-    let segments = parse_row_major::<usize>(input, "");
-    let segments = &segments[0];
+fn parse(input: &str) -> Vec<usize> {
+    parse_row_major::<usize>(input, "").swap_remove(0)
+}
 
+// TODO: Make soln more efficient/idiomatic
+fn part1(segments: &[usize]) -> String {
     let mut disk: Vec<Option<usize>> = Vec::new();
     segments.iter().enumerate().for_each(|(i, x)| {
         if i % 2 == 0 {
@@ -46,13 +65,10 @@ fn part1(input: &str) -> String {
         }
     }
 
-    return sum.to_string();
+    sum.to_string()
 }
 
-fn part2(input: &str) -> String {
-    let segments = parse_row_major::<usize>(input, "");
-    let segments = &segments[0];
-
+fn part2(segments: &[usize]) -> String {
     let mut disk: Vec<DiskBlock> = Vec::new();
 
     segments.iter().enumerate().fold(0, |idx, (i, x)| {
@@ -72,10 +88,10 @@ fn part2(input: &str) -> String {
     //    println!("{} {}", block.display(), block.checksum());
     //}
 
-    return defrag
+    defrag
         .iter()
         .fold(0, |acc, b| acc + b.checksum())
-        .to_string();
+        .to_string()
 }
 
 fn defrag2(disk: Vec<DiskBlock>) -> Vec<DiskBlock> {
@@ -116,7 +132,7 @@ fn defrag2(disk: Vec<DiskBlock>) -> Vec<DiskBlock> {
     defrag
 }
 
-fn shift_block(defrag: &mut Vec<DiskBlock>, j: usize, disk: &Vec<DiskBlock>, i: usize) {
+fn shift_block(defrag: &mut Vec<DiskBlock>, j: usize, disk: &[DiskBlock], i: usize) {
     defrag.splice(
         j..j,
         [DiskBlock::new(
@@ -139,11 +155,16 @@ fn shift_block(defrag: &mut Vec<DiskBlock>, j: usize, disk: &Vec<DiskBlock>, i:
     defrag[to_remove_idx].full = false;
 }
 
+#[allow(
+    dead_code,
+    reason = "debug dump, called from commented-out lines in part2 and defrag2"
+)]
 fn print_blocks(disk: &Vec<DiskBlock>) {
     block_repr(disk);
     println!();
 }
 
+#[allow(dead_code, reason = "used by print_blocks and the tests")]
 fn block_repr(disk: &Vec<DiskBlock>) -> String {
     let mut ret = String::new();
     for block in disk {
@@ -153,26 +174,13 @@ fn block_repr(disk: &Vec<DiskBlock>) -> String {
             }
         } else {
             for _ in 0..block.size {
-                ret.push_str(".");
+                ret.push('.');
             }
         }
     }
     ret
 }
 
-fn parse_segments(input: &str) -> Vec<DiskBlock> {
-    let segments = parse_row_major::<usize>(input, "");
-    let segments = &segments[0];
-
-    let mut disk: Vec<DiskBlock> = Vec::new();
-
-    segments.iter().enumerate().fold(0, |idx, (i, x)| {
-        disk.push(DiskBlock::new(*x, i / 2, idx, i % 2 == 0));
-        idx + x
-    });
-    disk
-}
-
 #[derive(Clone)]
 struct DiskBlock {
     size: usize,
@@ -193,12 +201,13 @@ impl DiskBlock {
 
     fn checksum(&self) -> usize {
         if self.full {
-            return self.idx * self.id * self.size + self.id * self.size * (self.size - 1) / 2;
+            self.idx * self.id * self.size + self.id * self.size * (self.size - 1) / 2
         } else {
-            return 0;
+            0
         }
     }
 
+    #[allow(dead_code, reason = "printed by the commented-out dumps in part2")]
     fn display(&self) -> String {
         format!(
             "size:{}, id:{}, idx:{}, full:{}",
@@ -207,7 +216,11 @@ impl DiskBlock {
     }
 }
 
-fn disk_checksum(disk: &Vec<usize>) -> usize {
+#[allow(
+    dead_code,
+    reason = "left over from part 1, which now sums as it compacts"
+)]
+fn disk_checksum(disk: &[usize]) -> usize {
     disk.iter()
         .enumerate()
         .fold(0, |acc, (i, val)| acc + i * val)
@@ -219,14 +232,27 @@ mod tests {
 
     static TEST_INPUT: &str = "2333133121414131402";
 
+    fn parse_segments(input: &str) -> Vec<DiskBlock> {
+        let segments = parse_row_major::<usize>(input, "");
+        let segments = &segments[0];
+
+        let mut disk: Vec<DiskBlock> = Vec::new();
+
+        segments.iter().enumerate().fold(0, |idx, (i, x)| {
+            disk.push(DiskBlock::new(*x, i / 2, idx, i % 2 == 0));
+            idx + x
+        });
+        disk
+    }
+
     #[test]
     fn test_part1() {
-        assert_eq!(part1(TEST_INPUT), "1928");
+        assert_eq!(part1(&parse(TEST_INPUT)), "1928");
     }
 
     #[test]
     fn test_part2() {
-        assert_eq!(part2(TEST_INPUT), "2858");
+        assert_eq!(part2(&parse(TEST_INPUT)), "2858");
     }
 
     #[test]
@@ -245,20 +271,23 @@ mod tests {
     #[test]
     fn test_part2_ex1() {
         assert_eq!(
-            part2("673253833464635054191677274350925861527651788483"),
+            part2(&parse("673253833464635054191677274350925861527651788483")),
             "149706"
         );
     }
 
     #[test]
     fn test_part2_ex2() {
-        assert_eq!(part2("23222120202525282820202020272722212121"), "7705");
+        assert_eq!(
+            part2(&parse("23222120202525282820202020272722212121")),
+            "7705"
+        );
     }
 
     #[test]
     fn test_part2_ex3() {
         assert_eq!(
-            part2("22222228282828222222282829212324252627282920"),
+            part2(&parse("22222228282828222222282829212324252627282920")),
             "9447"
         );
     }
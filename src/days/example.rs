@@ -0,0 +1,55 @@
+// Template for a new day: copy to dayN.rs, then add it to SOLUTIONS in days/mod.rs.
+use crate::solution::Solution;
+use crate::error::Error;
+use crate::*;
+
+pub struct Day0;
+
+impl Solution for Day0 {
+    type Input = String;
+
+    fn day(&self) -> i32 {
+        0
+    }
+
+    fn title(&self) -> &'static str {
+        ""
+    }
+
+    fn parse(&self, input: &str) -> Result<Self::Input, Error> {
+        Ok(input.to_string())
+    }
+
+    fn part1(&self, input: &Self::Input) -> String {
+        part1(input)
+    }
+
+    fn part2(&self, input: &Self::Input) -> String {
+        part2(input)
+    }
+}
+
+fn part1(input: &str) -> String {
+    "".to_string()
+}
+
+fn part2(input: &str) -> String {
+    "".to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    static TEST_INPUT: &str = "";
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(part1(TEST_INPUT), "0");
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(part2(TEST_INPUT), "0");
+    }
+}
@@ -0,0 +1,125 @@
+use std::{fmt, io, path::PathBuf};
+
+use crate::INPUT_DIR_VAR;
+
+#[derive(Debug)]
+pub enum Error {
+    /**
+     * No input file in any of the searched locations.
+     */
+    MissingInput {
+        day: i32,
+        searched: Vec<PathBuf>,
+    },
+    /**
+     * The file exists but couldn't be read (permissions, not UTF-8, ...)
+     */
+    Unreadable {
+        path: PathBuf,
+        source: io::Error,
+    },
+    EmptyInput {
+        path: PathBuf,
+    },
+    /**
+     * Asked for a day that can't exist.
+     */
+    WrongDay(i32),
+    /**
+     * The input was read fine but isn't what the puzzle looks like.
+     */
+    BadInput(Box<dyn std::error::Error + Send + Sync>),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::MissingInput { day, searched } => {
+                writeln!(f, "no input found for day {}; looked for:", day)?;
+                for path in searched {
+                    writeln!(f, "    {}", path.display())?;
+                }
+                write!(
+                    f,
+                    "set {} or pass --inputs <dir> to look somewhere else",
+                    INPUT_DIR_VAR
+                )
+            }
+            Error::Unreadable { path, source } => {
+                write!(f, "could not read {}: {}", path.display(), source)
+            }
+            Error::EmptyInput { path } => write!(f, "{} is empty", path.display()),
+            Error::WrongDay(day) => write!(f, "there is no day {}, days run from 1 to 25", day),
+            Error::BadInput(e) => write!(f, "bad input: {}", e),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Unreadable { source, .. } => Some(source),
+            Error::BadInput(e) => Some(e.as_ref()),
+            _ => None,
+        }
+    }
+}
+
+/**
+ * A line of input that didn't have the numbers it should have.
+ */
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseError {
+    /**
+     * Counting from 1, like an editor.
+     */
+    pub line: usize,
+    /**
+     * Byte offset into the line, from 1. Just past the end if the line ran out of numbers.
+     */
+    pub column: usize,
+    /**
+     * The whole line.
+     */
+    pub text: String,
+    pub kind: ParseErrorKind,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ParseErrorKind {
+    TooFew {
+        wanted: usize,
+        found: usize,
+    },
+    TooMany {
+        wanted: usize,
+    },
+    /**
+     * Doesn't fit in the type being parsed into, e.g. a negative coordinate for a `usize`.
+     */
+    OutOfRange,
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "line {}, column {}: ", self.line, self.column)?;
+        match self.kind {
+            ParseErrorKind::TooFew { wanted, found } => {
+                write!(f, "expected {} numbers, found {}", wanted, found)?
+            }
+            ParseErrorKind::TooMany { wanted } => {
+                write!(f, "expected {} numbers, found more", wanted)?
+            }
+            ParseErrorKind::OutOfRange => write!(f, "number out of range")?,
+        }
+        write!(f, " in {:?}", self.text)
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+impl From<ParseError> for Error {
+    fn from(e: ParseError) -> Self {
+        Error::BadInput(Box::new(e))
+    }
+}
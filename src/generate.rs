@@ -0,0 +1,268 @@
+use crate::*;
+
+/**
+ * A small LCG. Plenty random enough for making up inputs, and the same seed always gives the
+ * same input on every machine.
+ */
+#[derive(Clone, Debug)]
+pub struct Rng(u64);
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        // mix the seed a bit so nearby seeds don't start out in step
+        let mut rng = Rng(seed ^ 0x9e37_79b9_7f4a_7c15);
+        rng.next_u64();
+        rng
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.0 = self
+            .0
+            .wrapping_mul(6364136223846793005)
+            .wrapping_add(1442695040888963407);
+        self.0 >> 33
+    }
+
+    /**
+     * Uniform-ish in `0..n`.
+     */
+    pub fn below(&mut self, n: usize) -> usize {
+        (self.next_u64() % n as u64) as usize
+    }
+
+    /**
+     * True with probability `p`.
+     */
+    pub fn chance(&mut self, p: f64) -> bool {
+        (self.next_u64() as f64) < p * (1u64 << 31) as f64
+    }
+
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            items.swap(i, self.below(i + 1));
+        }
+    }
+}
+
+/**
+ * Knobs for `reindeer_maze`.
+ */
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct MazeOptions {
+    /**
+     * Rounded up to odd sizes, so the corridors and walls line up. At least 5.
+     */
+    pub width: usize,
+    pub height: usize,
+    /**
+     * Chance of knocking out each wall between two corridors after carving, making loops. 0 is a
+     * perfect maze with exactly one way between any two tiles.
+     */
+    pub loopiness: f64,
+    /**
+     * Share of the dead ends to keep. The rest get opened up into a neighbouring corridor.
+     */
+    pub dead_ends: f64,
+}
+
+impl Default for MazeOptions {
+    /**
+     * Roughly the shape of a real day 16 input.
+     */
+    fn default() -> Self {
+        MazeOptions {
+            width: 141,
+            height: 141,
+            loopiness: 0.05,
+            dead_ends: 0.5,
+        }
+    }
+}
+
+/**
+ * A day 16 maze: walls all round, `S` in the bottom-left corner and `E` in the top-right, with
+ * corridors on the odd rows and columns carved by a depth-first search.
+ */
+pub fn reindeer_maze(seed: u64, options: &MazeOptions) -> String {
+    let odd = |n: usize| n.max(5) | 1;
+    let (width, height) = (odd(options.width), odd(options.height));
+    let mut rng = Rng::new(seed);
+    let mut grid = Grid::new(width, height, '#');
+
+    // depth first from the start, with an explicit stack since big mazes go very deep
+    let start = (height - 2, 1);
+    grid[start] = '.';
+    let mut stack = vec![start];
+    while let Some(&cell) = stack.last() {
+        let mut unvisited: Vec<Direction> = Direction::ALL
+            .into_iter()
+            .filter(|&d| jump(&grid, cell, d).is_some_and(|next| grid[next] == '#'))
+            .collect();
+        if unvisited.is_empty() {
+            stack.pop();
+            continue;
+        }
+        rng.shuffle(&mut unvisited);
+        let (gap, next) = (
+            grid.offset(cell, unvisited[0]).unwrap(),
+            jump(&grid, cell, unvisited[0]).unwrap(),
+        );
+        grid[gap] = '.';
+        grid[next] = '.';
+        stack.push(next);
+    }
+
+    // walls with corridor on both sides
+    let inner_walls: Vec<(usize, usize)> = grid
+        .positions()
+        .filter(|&(i, j)| (i % 2 == 1) != (j % 2 == 1) && grid[(i, j)] == '#')
+        .filter(|&(i, j)| {
+            let across = |d: Direction| {
+                grid.offset((i, j), d)
+                    .is_some_and(|p| grid[p] == '.' && p.0 % 2 == 1 && p.1 % 2 == 1)
+            };
+            (across(Direction::Up) && across(Direction::Down))
+                || (across(Direction::Left) && across(Direction::Right))
+        })
+        .collect();
+    for wall in inner_walls {
+        if rng.chance(options.loopiness) {
+            grid[wall] = '.';
+        }
+    }
+
+    for cell in grid.positions().filter(|&(i, j)| i % 2 == 1 && j % 2 == 1) {
+        let walls: Vec<Direction> = Direction::ALL
+            .into_iter()
+            .filter(|&d| grid[grid.offset(cell, d).unwrap()] == '#')
+            .collect();
+        if walls.len() < 3 || rng.chance(options.dead_ends) {
+            continue;
+        }
+        let through: Vec<Direction> = walls
+            .into_iter()
+            .filter(|&d| jump(&grid, cell, d).is_some())
+            .collect();
+        if !through.is_empty() {
+            let gap = grid
+                .offset(cell, through[rng.below(through.len())])
+                .unwrap();
+            grid[gap] = '.';
+        }
+    }
+
+    grid[start] = 'S';
+    grid[(1, width - 2)] = 'E';
+    grid.to_string()
+}
+
+/**
+ * The corridor tile two steps from `cell`, if it's inside the outer wall.
+ */
+fn jump(grid: &Grid<char>, cell: (usize, usize), d: Direction) -> Option<(usize, usize)> {
+    let next = grid.offset(grid.offset(cell, d)?, d)?;
+    let inside =
+        next.0 > 0 && next.1 > 0 && next.0 < grid.height() - 1 && next.1 < grid.width() - 1;
+    inside.then_some(next)
+}
+
+/**
+ * A day 18 input: `count` distinct cells of a `size` by `size` memory space as `x,y` lines, in
+ * the order they fall. Never the two corners, so there's a way through until enough have fallen.
+ */
+pub fn falling_bytes(seed: u64, size: usize, count: usize) -> String {
+    let mut rng = Rng::new(seed);
+    let mut cells: Vec<(usize, usize)> = (0..size * size)
+        .map(|i| (i % size, i / size))
+        .filter(|&cell| cell != (0, 0) && cell != (size - 1, size - 1))
+        .collect();
+    rng.shuffle(&mut cells);
+    cells.truncate(count);
+    let lines: Vec<String> = cells.iter().map(|(x, y)| format!("{},{}", x, y)).collect();
+    lines.join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use super::*;
+
+    fn options(loopiness: f64, dead_ends: f64) -> MazeOptions {
+        MazeOptions {
+            width: 41,
+            height: 31,
+            loopiness,
+            dead_ends,
+        }
+    }
+
+    fn dead_ends(grid: &Grid<char>) -> usize {
+        grid.positions()
+            .filter(|&pos| grid[pos] != '#')
+            .filter(|&pos| grid.neighbours4(pos).filter(|&n| grid[n] == '#').count() == 3)
+            .count()
+    }
+
+    fn open_tiles(grid: &Grid<char>) -> usize {
+        grid.iter().filter(|(_, &c)| c != '#').count()
+    }
+
+    #[test]
+    fn test_reindeer_maze() {
+        let maze = reindeer_maze(16, &options(0.0, 1.0));
+        assert_eq!(maze, reindeer_maze(16, &options(0.0, 1.0)));
+        assert_ne!(maze, reindeer_maze(17, &options(0.0, 1.0)));
+
+        let grid = Grid::parse(&maze);
+        assert_eq!((grid.width(), grid.height()), (41, 31));
+        assert_eq!(grid[(29, 1)], 'S');
+        assert_eq!(grid[(1, 39)], 'E');
+        assert!(grid.row(0).iter().chain(grid.row(30)).all(|&c| c == '#'));
+        assert!(grid.column(0).chain(grid.column(40)).all(|&c| c == '#'));
+
+        // a perfect maze is a tree: all 20 * 15 corridor crossings joined up by 299 gaps, and
+        // one fewer link between neighbouring tiles than there are tiles
+        assert_eq!(open_tiles(&grid), 300 + 299);
+        let links = grid
+            .positions()
+            .filter(|&pos| grid[pos] != '#')
+            .flat_map(|pos| grid.neighbours4(pos).filter(|&n| grid[n] != '#'))
+            .count()
+            / 2;
+        assert_eq!(links, open_tiles(&grid) - 1);
+    }
+
+    #[test]
+    fn test_maze_knobs() {
+        let perfect = Grid::parse(&reindeer_maze(5, &options(0.0, 1.0)));
+        let loopy = Grid::parse(&reindeer_maze(5, &options(0.3, 1.0)));
+        let braided = Grid::parse(&reindeer_maze(5, &options(0.0, 0.0)));
+        assert!(open_tiles(&loopy) > open_tiles(&perfect));
+        assert!(dead_ends(&perfect) > 20);
+        assert_eq!(dead_ends(&braided), 0);
+        // even sizes round up
+        let odd = MazeOptions {
+            width: 10,
+            height: 2,
+            ..MazeOptions::default()
+        };
+        let grid = Grid::parse(&reindeer_maze(1, &odd));
+        assert_eq!((grid.width(), grid.height()), (11, 5));
+    }
+
+    #[test]
+    fn test_falling_bytes() {
+        let bytes = falling_bytes(18, 71, 3000);
+        assert_eq!(bytes, falling_bytes(18, 71, 3000));
+        let coords = parse_coords(&bytes).unwrap();
+        assert_eq!(coords.len(), 3000);
+        assert_eq!(coords.iter().collect::<HashSet<_>>().len(), 3000);
+        assert!(coords
+            .iter()
+            .all(|&(y, x)| (0..71).contains(&x) && (0..71).contains(&y)));
+        assert!(!coords.contains(&(0, 0)) && !coords.contains(&(70, 70)));
+        // asking for more than fits gives every cell bar the corners
+        assert_eq!(parse_coords(&falling_bytes(1, 5, 100)).unwrap().len(), 23);
+    }
+}
@@ -0,0 +1,396 @@
+use std::{
+    collections::{HashMap, HashSet, VecDeque},
+    fmt,
+    hash::Hash,
+};
+
+/**
+ * A directed graph over any hashable node type. Edges are kept both ways round so it can be
+ * walked forwards and backwards. Nodes remember the order they were added in, which keeps
+ * sorts and exports deterministic.
+ */
+#[derive(Debug, Clone)]
+pub struct DiGraph<T> {
+    nodes: Vec<T>,
+    index: HashMap<T, usize>,
+    out_edges: Vec<Vec<usize>>,
+    in_edges: Vec<Vec<usize>>,
+}
+
+/**
+ * Returned by `topo_sort` when the graph isn't a DAG. `nodes` is one cycle, in edge order: each
+ * node has an edge to the next, and the last has an edge back to the first.
+ */
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Cycle<T> {
+    pub nodes: Vec<T>,
+}
+
+impl<T: fmt::Debug> fmt::Display for Cycle<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "cycle: ")?;
+        for node in &self.nodes {
+            write!(f, "{:?} -> ", node)?;
+        }
+        match self.nodes.first() {
+            Some(first) => write!(f, "{:?}", first),
+            None => Ok(()),
+        }
+    }
+}
+
+impl<T: fmt::Debug> std::error::Error for Cycle<T> {}
+
+impl<T: Eq + Hash + Clone> DiGraph<T> {
+    pub fn new() -> Self {
+        DiGraph {
+            nodes: Vec::new(),
+            index: HashMap::new(),
+            out_edges: Vec::new(),
+            in_edges: Vec::new(),
+        }
+    }
+
+    /**
+     * Builds a graph from (from, to) pairs.
+     */
+    pub fn from_edges(edges: impl IntoIterator<Item = (T, T)>) -> Self {
+        let mut graph = Self::new();
+        for (from, to) in edges {
+            graph.add_edge(from, to);
+        }
+        graph
+    }
+
+    /**
+     * Adds a node with no edges, if it isn't in the graph already.
+     */
+    pub fn add_node(&mut self, node: T) -> usize {
+        if let Some(&i) = self.index.get(&node) {
+            return i;
+        }
+        let i = self.nodes.len();
+        self.index.insert(node.clone(), i);
+        self.nodes.push(node);
+        self.out_edges.push(Vec::new());
+        self.in_edges.push(Vec::new());
+        i
+    }
+
+    /**
+     * Adds both nodes if needed. Adding an edge twice doesn't duplicate it.
+     */
+    pub fn add_edge(&mut self, from: T, to: T) {
+        let from = self.add_node(from);
+        let to = self.add_node(to);
+        if !self.out_edges[from].contains(&to) {
+            self.out_edges[from].push(to);
+            self.in_edges[to].push(from);
+        }
+    }
+
+    /**
+     * Clears all graph edges going in to a certain vertex.
+     */
+    pub fn clear_into(&mut self, to: &T) {
+        let Some(&to) = self.index.get(to) else {
+            return;
+        };
+        for from in std::mem::take(&mut self.in_edges[to]) {
+            self.out_edges[from].retain(|&n| n != to);
+        }
+    }
+
+    pub fn contains(&self, node: &T) -> bool {
+        self.index.contains_key(node)
+    }
+
+    pub fn has_edge(&self, from: &T, to: &T) -> bool {
+        match (self.index.get(from), self.index.get(to)) {
+            (Some(&from), Some(&to)) => self.out_edges[from].contains(&to),
+            _ => false,
+        }
+    }
+
+    pub fn node_count(&self) -> usize {
+        self.nodes.len()
+    }
+
+    pub fn edge_count(&self) -> usize {
+        self.out_edges.iter().map(|e| e.len()).sum()
+    }
+
+    /**
+     * Nodes in the order they were added.
+     */
+    pub fn nodes(&self) -> &[T] {
+        &self.nodes
+    }
+
+    pub fn edges(&self) -> impl Iterator<Item = (&T, &T)> {
+        self.out_edges
+            .iter()
+            .enumerate()
+            .flat_map(move |(from, tos)| {
+                tos.iter()
+                    .map(move |&to| (&self.nodes[from], &self.nodes[to]))
+            })
+    }
+
+    pub fn successors(&self, node: &T) -> impl Iterator<Item = &T> {
+        self.neighbours(node, &self.out_edges)
+    }
+
+    pub fn predecessors(&self, node: &T) -> impl Iterator<Item = &T> {
+        self.neighbours(node, &self.in_edges)
+    }
+
+    fn neighbours<'a>(&'a self, node: &T, edges: &'a [Vec<usize>]) -> impl Iterator<Item = &'a T> {
+        let ids = match self.index.get(node) {
+            Some(&i) => edges[i].as_slice(),
+            None => &[],
+        };
+        ids.iter().map(move |&i| &self.nodes[i])
+    }
+
+    /**
+     * Every node reachable from any of `starts`, starts included.
+     */
+    pub fn reachable_from<'a>(&self, starts: impl IntoIterator<Item = &'a T>) -> HashSet<T>
+    where
+        T: 'a,
+    {
+        self.flood(starts, &self.out_edges)
+    }
+
+    /**
+     * Every node that can reach any of `targets`, walking edges backwards. Targets included.
+     */
+    pub fn reaching<'a>(&self, targets: impl IntoIterator<Item = &'a T>) -> HashSet<T>
+    where
+        T: 'a,
+    {
+        self.flood(targets, &self.in_edges)
+    }
+
+    fn flood<'a>(&self, from: impl IntoIterator<Item = &'a T>, edges: &[Vec<usize>]) -> HashSet<T>
+    where
+        T: 'a,
+    {
+        let mut seen = vec![false; self.nodes.len()];
+        let mut to_visit: Vec<usize> = from
+            .into_iter()
+            .filter_map(|n| self.index.get(n).copied())
+            .collect();
+        while let Some(cur) = to_visit.pop() {
+            if !seen[cur] {
+                seen[cur] = true;
+                to_visit.extend(&edges[cur]);
+            }
+        }
+        self.ids_to_nodes(seen)
+    }
+
+    fn ids_to_nodes(&self, keep: Vec<bool>) -> HashSet<T> {
+        keep.iter()
+            .enumerate()
+            .filter(|(_, &k)| k)
+            .map(|(i, _)| self.nodes[i].clone())
+            .collect()
+    }
+
+    /**
+     * Kahn's algorithm. Among nodes that are free at the same time, earlier-added ones come first.
+     */
+    pub fn topo_sort(&self) -> Result<Vec<T>, Cycle<T>> {
+        let mut incoming: Vec<usize> = self.in_edges.iter().map(|e| e.len()).collect();
+        let mut to_explore: VecDeque<usize> = (0..self.nodes.len())
+            .filter(|&i| incoming[i] == 0)
+            .collect();
+        let mut sorted = Vec::with_capacity(self.nodes.len());
+
+        while let Some(cur) = to_explore.pop_front() {
+            sorted.push(self.nodes[cur].clone());
+            for &next in &self.out_edges[cur] {
+                incoming[next] -= 1;
+                if incoming[next] == 0 {
+                    to_explore.push_back(next);
+                }
+            }
+        }
+
+        if sorted.len() == self.nodes.len() {
+            Ok(sorted)
+        } else {
+            Err(self.find_cycle(&incoming))
+        }
+    }
+
+    /**
+     * Called with Kahn's leftover in-degrees: every node still above 0 has a predecessor that is
+     * also above 0, so walking predecessors from any of them must loop.
+     */
+    fn find_cycle(&self, incoming: &[usize]) -> Cycle<T> {
+        let mut cur = incoming.iter().position(|&d| d > 0).unwrap();
+        let mut visited_at: HashMap<usize, usize> = HashMap::new();
+        let mut walk = Vec::new();
+        while !visited_at.contains_key(&cur) {
+            visited_at.insert(cur, walk.len());
+            walk.push(cur);
+            cur = *self.in_edges[cur]
+                .iter()
+                .find(|&&p| incoming[p] > 0)
+                .unwrap();
+        }
+        // walk went backwards along edges, so flip the loop round
+        let mut nodes: Vec<T> = walk[visited_at[&cur]..]
+            .iter()
+            .map(|&i| self.nodes[i].clone())
+            .collect();
+        nodes.reverse();
+        Cycle { nodes }
+    }
+
+    /**
+     * Kosaraju's algorithm. Components come out in topological order of the condensed graph: no
+     * edge leads from a later component back to an earlier one.
+     */
+    pub fn strongly_connected_components(&self) -> Vec<Vec<T>> {
+        let n = self.nodes.len();
+
+        // first pass: order nodes by when their DFS finishes
+        let mut finished = Vec::with_capacity(n);
+        let mut seen = vec![false; n];
+        for root in 0..n {
+            if seen[root] {
+                continue;
+            }
+            seen[root] = true;
+            let mut stack = vec![(root, 0)];
+            while let Some((node, next_edge)) = stack.pop() {
+                if let Some(&next) = self.out_edges[node].get(next_edge) {
+                    stack.push((node, next_edge + 1));
+                    if !seen[next] {
+                        seen[next] = true;
+                        stack.push((next, 0));
+                    }
+                } else {
+                    finished.push(node);
+                }
+            }
+        }
+
+        // second pass: flood the reversed graph, latest finisher first
+        let mut component_of = vec![usize::MAX; n];
+        let mut components = Vec::new();
+        for &root in finished.iter().rev() {
+            if component_of[root] != usize::MAX {
+                continue;
+            }
+            let id = components.len();
+            let mut component = Vec::new();
+            let mut stack = vec![root];
+            component_of[root] = id;
+            while let Some(node) = stack.pop() {
+                component.push(self.nodes[node].clone());
+                for &prev in &self.in_edges[node] {
+                    if component_of[prev] == usize::MAX {
+                        component_of[prev] = id;
+                        stack.push(prev);
+                    }
+                }
+            }
+            components.push(component);
+        }
+        components
+    }
+
+    /**
+     * Graphviz source for the graph, labelling each node with `label`.
+     */
+    pub fn to_dot(&self, label: impl Fn(&T) -> String) -> String {
+        let mut dot = String::from("digraph {\n");
+        for (i, node) in self.nodes.iter().enumerate() {
+            let text = label(node).replace('\\', "\\\\").replace('"', "\\\"");
+            dot.push_str(&format!("    n{} [label=\"{}\"];\n", i, text));
+        }
+        for (from, tos) in self.out_edges.iter().enumerate() {
+            for to in tos {
+                dot.push_str(&format!("    n{} -> n{};\n", from, to));
+            }
+        }
+        dot.push_str("}\n");
+        dot
+    }
+}
+
+impl<T: Eq + Hash + Clone> Default for DiGraph<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_topo_sort() {
+        let graph = DiGraph::from_edges([(5, 11), (7, 11), (7, 8), (3, 8), (3, 10), (11, 2)]);
+        let sorted = graph.topo_sort().unwrap();
+        assert_eq!(sorted.len(), 7);
+        for (from, to) in graph.edges() {
+            let pos = |n| sorted.iter().position(|x| x == n).unwrap();
+            assert!(pos(from) < pos(to));
+        }
+    }
+
+    #[test]
+    fn test_cycle_reporting() {
+        let graph = DiGraph::from_edges([("a", "b"), ("b", "c"), ("c", "d"), ("d", "b")]);
+        let cycle = graph.topo_sort().unwrap_err();
+        assert_eq!(cycle.nodes.len(), 3);
+        for (i, from) in cycle.nodes.iter().enumerate() {
+            let to = &cycle.nodes[(i + 1) % cycle.nodes.len()];
+            assert!(graph.has_edge(from, to));
+        }
+        assert!(cycle.to_string().starts_with("cycle: "));
+    }
+
+    #[test]
+    fn test_reachability() {
+        let mut graph = DiGraph::from_edges([(1, 2), (2, 3), (4, 3), (3, 5)]);
+        graph.add_node(6);
+        assert_eq!(graph.reachable_from(&[2]), HashSet::from([2, 3, 5]));
+        assert_eq!(graph.reaching(&[3]), HashSet::from([1, 2, 3, 4]));
+        assert_eq!(graph.reaching(&[6]), HashSet::from([6]));
+
+        graph.clear_into(&3);
+        assert_eq!(graph.reaching(&[5]), HashSet::from([3, 5]));
+        assert_eq!(graph.successors(&2).count(), 0);
+        assert_eq!(graph.edge_count(), 2);
+    }
+
+    #[test]
+    fn test_scc() {
+        let graph = DiGraph::from_edges([(1, 2), (2, 3), (3, 1), (3, 4), (4, 5), (5, 4), (5, 6)]);
+        let components: Vec<Vec<i32>> = graph
+            .strongly_connected_components()
+            .into_iter()
+            .map(|mut c| {
+                c.sort();
+                c
+            })
+            .collect();
+        assert_eq!(components, vec![vec![1, 2, 3], vec![4, 5], vec![6]]);
+    }
+
+    #[test]
+    fn test_dot() {
+        let graph = DiGraph::from_edges([("x", "y\"z")]);
+        assert_eq!(
+            graph.to_dot(|n| n.to_string()),
+            "digraph {\n    n0 [label=\"x\"];\n    n1 [label=\"y\\\"z\"];\n    n0 -> n1;\n}\n"
+        );
+    }
+}
@@ -0,0 +1,318 @@
+use std::{
+    fmt,
+    ops::{Index, IndexMut},
+};
+
+use crate::{parse_string_array, Direction, Point};
+
+/**
+ * Offsets to all eight surrounding cells, going clockwise from down.
+ */
+pub const DELTAS_8: [(i32, i32); 8] = [
+    (1, 0),
+    (1, -1),
+    (0, -1),
+    (-1, -1),
+    (-1, 0),
+    (-1, 1),
+    (0, 1),
+    (1, 1),
+];
+
+/**
+ * A rectangular grid stored row-major in a single Vec.
+ * Positions are (row, column), the same way `grid[i][j]` indexed a `Vec<Vec<T>>`.
+ */
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    cells: Vec<T>,
+    width: usize,
+    height: usize,
+}
+
+impl<T> Grid<T> {
+    /**
+     * Panics if the rows aren't all the same length.
+     */
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Self {
+        let height = rows.len();
+        let width = rows.first().map_or(0, |row| row.len());
+        let mut cells = Vec::with_capacity(width * height);
+        for (i, row) in rows.into_iter().enumerate() {
+            assert_eq!(
+                row.len(),
+                width,
+                "row {} has length {}, expected {}",
+                i,
+                row.len(),
+                width
+            );
+            cells.extend(row);
+        }
+        Grid {
+            cells,
+            width,
+            height,
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn in_bounds(&self, pos: (usize, usize)) -> bool {
+        pos.0 < self.height && pos.1 < self.width
+    }
+
+    pub fn get(&self, pos: (usize, usize)) -> Option<&T> {
+        if self.in_bounds(pos) {
+            Some(&self.cells[pos.0 * self.width + pos.1])
+        } else {
+            None
+        }
+    }
+
+    pub fn get_mut(&mut self, pos: (usize, usize)) -> Option<&mut T> {
+        if self.in_bounds(pos) {
+            Some(&mut self.cells[pos.0 * self.width + pos.1])
+        } else {
+            None
+        }
+    }
+
+    /**
+     * `pos` moved by `delta` (a `Point`, `Direction` or `(i32, i32)`), or None if that falls off
+     * the grid.
+     */
+    pub fn offset(&self, pos: (usize, usize), delta: impl Into<Point>) -> Option<(usize, usize)> {
+        delta
+            .into()
+            .add_to(pos)
+            .filter(|&moved| self.in_bounds(moved))
+    }
+
+    /**
+     * Position of a signed point, if it's on the grid.
+     */
+    pub fn locate(&self, point: Point) -> Option<(usize, usize)> {
+        point.to_usize().filter(|&pos| self.in_bounds(pos))
+    }
+
+    /**
+     * In-bounds orthogonal neighbours of `pos`, in `Direction::ALL` order.
+     */
+    pub fn neighbours4(&self, pos: (usize, usize)) -> impl Iterator<Item = (usize, usize)> + '_ {
+        Direction::ALL
+            .iter()
+            .filter_map(move |&direction| self.offset(pos, direction))
+    }
+
+    /**
+     * In-bounds neighbours of `pos` including diagonals, in `DELTAS_8` order.
+     */
+    pub fn neighbours8(&self, pos: (usize, usize)) -> impl Iterator<Item = (usize, usize)> + '_ {
+        DELTAS_8
+            .iter()
+            .filter_map(move |&delta| self.offset(pos, delta))
+    }
+
+    /**
+     * Every position in row-major order.
+     */
+    pub fn positions(&self) -> impl Iterator<Item = (usize, usize)> {
+        let width = self.width;
+        (0..self.height).flat_map(move |i| (0..width).map(move |j| (i, j)))
+    }
+
+    /**
+     * Every cell with its position, in row-major order.
+     */
+    pub fn iter(&self) -> impl Iterator<Item = ((usize, usize), &T)> {
+        self.positions().zip(self.cells.iter())
+    }
+
+    pub fn row(&self, i: usize) -> &[T] {
+        &self.cells[i * self.width..(i + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        // chunks panics on 0, and an empty grid has no rows anyway
+        self.cells.chunks(self.width.max(1))
+    }
+
+    pub fn column(&self, j: usize) -> impl Iterator<Item = &T> {
+        assert!(j < self.width, "column {} out of bounds", j);
+        self.cells.iter().skip(j).step_by(self.width)
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(move |j| self.column(j))
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            cells: self.cells.iter().map(f).collect(),
+            width: self.width,
+            height: self.height,
+        }
+    }
+}
+
+impl<T: Clone> Grid<T> {
+    pub fn new(width: usize, height: usize, fill: T) -> Self {
+        Grid {
+            cells: vec![fill; width * height],
+            width,
+            height,
+        }
+    }
+}
+
+impl<T: PartialEq> Grid<T> {
+    /**
+     * Position of the first cell equal to `value`, scanning row by row.
+     */
+    pub fn find(&self, value: &T) -> Option<(usize, usize)> {
+        self.iter()
+            .find(|(_, cell)| *cell == value)
+            .map(|(pos, _)| pos)
+    }
+
+    pub fn find_all<'a>(&'a self, value: &'a T) -> impl Iterator<Item = (usize, usize)> + 'a {
+        self.iter()
+            .filter(move |(_, cell)| *cell == value)
+            .map(|(pos, _)| pos)
+    }
+}
+
+impl Grid<char> {
+    /**
+     * One row per non-empty line, as with `parse_string_array`.
+     */
+    pub fn parse(input: &str) -> Self {
+        Grid::from_rows(parse_string_array(input))
+    }
+}
+
+impl<T> From<Vec<Vec<T>>> for Grid<T> {
+    fn from(rows: Vec<Vec<T>>) -> Self {
+        Grid::from_rows(rows)
+    }
+}
+
+impl<T> Index<(usize, usize)> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, pos: (usize, usize)) -> &T {
+        match self.get(pos) {
+            Some(cell) => cell,
+            None => panic!(
+                "{:?} out of bounds for {}x{} grid",
+                pos, self.height, self.width
+            ),
+        }
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Grid<T> {
+    fn index_mut(&mut self, pos: (usize, usize)) -> &mut T {
+        let (height, width) = (self.height, self.width);
+        match self.get_mut(pos) {
+            Some(cell) => cell,
+            None => panic!("{:?} out of bounds for {}x{} grid", pos, height, width),
+        }
+    }
+}
+
+/**
+ * Rows on separate lines, cells printed back to back, so a `Grid<char>` prints as the puzzle
+ * input it came from.
+ */
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, row) in self.rows().enumerate() {
+            if i > 0 {
+                writeln!(f)?;
+            }
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    static TEST_INPUT: &str = "#.#
+.S.
+##E
+";
+
+    #[test]
+    fn test_parse_and_display() {
+        let grid = Grid::parse(TEST_INPUT);
+        assert_eq!(grid.width(), 3);
+        assert_eq!(grid.height(), 3);
+        assert_eq!(grid[(1, 1)], 'S');
+        assert_eq!(grid.to_string(), TEST_INPUT.trim_end());
+    }
+
+    #[test]
+    fn test_bounds() {
+        let mut grid = Grid::parse(TEST_INPUT);
+        assert_eq!(grid.get((2, 3)), None);
+        assert_eq!(grid.get((3, 0)), None);
+        assert_eq!(grid.offset((0, 0), (-1, 0)), None);
+        assert_eq!(grid.offset((0, 0), (1, 2)), Some((1, 2)));
+        assert_eq!(grid.offset((2, 2), Direction::Right), None);
+        assert_eq!(grid.locate(Point::new(1, -1)), None);
+        assert_eq!(grid.locate(Point::new(1, 2)), Some((1, 2)));
+
+        *grid.get_mut((0, 1)).unwrap() = 'O';
+        assert_eq!(grid.row(0), &['#', 'O', '#']);
+    }
+
+    #[test]
+    fn test_neighbours() {
+        let grid = Grid::new(3, 3, 0);
+        let corner: Vec<_> = grid.neighbours4((0, 0)).collect();
+        assert_eq!(corner, vec![(1, 0), (0, 1)]);
+        assert_eq!(grid.neighbours4((1, 1)).count(), 4);
+        assert_eq!(grid.neighbours8((0, 2)).count(), 3);
+        assert_eq!(grid.neighbours8((1, 1)).count(), 8);
+    }
+
+    #[test]
+    fn test_find() {
+        let grid = Grid::parse(TEST_INPUT);
+        assert_eq!(grid.find(&'E'), Some((2, 2)));
+        assert_eq!(grid.find(&'?'), None);
+        let walls: Vec<_> = grid.find_all(&'#').collect();
+        assert_eq!(walls, vec![(0, 0), (0, 2), (2, 0), (2, 1)]);
+    }
+
+    #[test]
+    fn test_rows_and_columns() {
+        let grid = Grid::from_rows(vec![vec![1, 2, 3], vec![4, 5, 6]]);
+        let rows: Vec<&[i32]> = grid.rows().collect();
+        assert_eq!(rows, vec![&[1, 2, 3], &[4, 5, 6]]);
+        let middle: Vec<_> = grid.column(1).copied().collect();
+        assert_eq!(middle, vec![2, 5]);
+        let sums: Vec<i32> = grid.columns().map(|c| c.sum()).collect();
+        assert_eq!(sums, vec![5, 7, 9]);
+        assert_eq!(grid.map(|x| x * 2)[(1, 2)], 12);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_ragged_rows() {
+        Grid::from_rows(vec![vec![1, 2], vec![3]]);
+    }
+}
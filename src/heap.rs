@@ -0,0 +1,196 @@
+use std::{collections::HashMap, hash::Hash};
+
+/**
+ * Binary min-heap that knows where each item lives, so priorities can be lowered in place.
+ * Each item is in the heap at most once: there are no stale entries to skip on pop.
+ */
+#[derive(Debug, Clone)]
+pub struct IndexedHeap<T, P> {
+    heap: Vec<(P, T)>,
+    index: HashMap<T, usize>,
+}
+
+impl<T: Eq + Hash + Clone, P: Ord> IndexedHeap<T, P> {
+    pub fn new() -> Self {
+        IndexedHeap {
+            heap: Vec::new(),
+            index: HashMap::new(),
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.heap.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.heap.is_empty()
+    }
+
+    pub fn contains(&self, item: &T) -> bool {
+        self.index.contains_key(item)
+    }
+
+    pub fn priority_of(&self, item: &T) -> Option<&P> {
+        self.index.get(item).map(|&i| &self.heap[i].0)
+    }
+
+    /**
+     * Adds `item`. If it's already queued, keeps whichever priority is lower.
+     * Returns true if the heap changed.
+     */
+    pub fn push(&mut self, item: T, priority: P) -> bool {
+        if self.contains(&item) {
+            return self.decrease_key(&item, priority);
+        }
+        let i = self.heap.len();
+        self.index.insert(item.clone(), i);
+        self.heap.push((priority, item));
+        self.sift_up(i);
+        true
+    }
+
+    /**
+     * Lowers the priority of a queued item. Returns false, leaving the heap alone, if the item
+     * isn't queued or `priority` isn't lower than its current one.
+     */
+    pub fn decrease_key(&mut self, item: &T, priority: P) -> bool {
+        let Some(&i) = self.index.get(item) else {
+            return false;
+        };
+        if priority >= self.heap[i].0 {
+            return false;
+        }
+        self.heap[i].0 = priority;
+        self.sift_up(i);
+        true
+    }
+
+    pub fn peek_min(&self) -> Option<(&T, &P)> {
+        self.heap.first().map(|(p, t)| (t, p))
+    }
+
+    pub fn pop_min(&mut self) -> Option<(T, P)> {
+        if self.heap.is_empty() {
+            return None;
+        }
+        let last = self.heap.len() - 1;
+        self.swap(0, last);
+        let (priority, item) = self.heap.pop().unwrap();
+        self.index.remove(&item);
+        if !self.heap.is_empty() {
+            self.sift_down(0);
+        }
+        Some((item, priority))
+    }
+
+    fn swap(&mut self, a: usize, b: usize) {
+        self.heap.swap(a, b);
+        *self.index.get_mut(&self.heap[a].1).unwrap() = a;
+        *self.index.get_mut(&self.heap[b].1).unwrap() = b;
+    }
+
+    fn sift_up(&mut self, mut i: usize) {
+        while i > 0 {
+            let parent = (i - 1) / 2;
+            if self.heap[i].0 >= self.heap[parent].0 {
+                break;
+            }
+            self.swap(i, parent);
+            i = parent;
+        }
+    }
+
+    fn sift_down(&mut self, mut i: usize) {
+        loop {
+            let mut smallest = i;
+            for child in [2 * i + 1, 2 * i + 2] {
+                if child < self.heap.len() && self.heap[child].0 < self.heap[smallest].0 {
+                    smallest = child;
+                }
+            }
+            if smallest == i {
+                break;
+            }
+            self.swap(i, smallest);
+            i = smallest;
+        }
+    }
+}
+
+impl<T: Eq + Hash + Clone, P: Ord> Default for IndexedHeap<T, P> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generate::Rng;
+
+    #[test]
+    fn test_pop_in_order() {
+        let mut heap = IndexedHeap::new();
+        for (item, priority) in [('a', 5), ('b', 1), ('c', 4), ('d', 2), ('e', 3)] {
+            assert!(heap.push(item, priority));
+        }
+        let mut popped = Vec::new();
+        while let Some((item, _)) = heap.pop_min() {
+            popped.push(item);
+        }
+        assert_eq!(popped, vec!['b', 'd', 'e', 'c', 'a']);
+        assert!(heap.is_empty());
+    }
+
+    #[test]
+    fn test_decrease_key() {
+        let mut heap = IndexedHeap::new();
+        heap.push("far", 10);
+        heap.push("near", 3);
+        assert!(heap.decrease_key(&"far", 1));
+        assert!(!heap.decrease_key(&"far", 7));
+        assert!(!heap.decrease_key(&"missing", 0));
+        assert_eq!(heap.priority_of(&"far"), Some(&1));
+        assert_eq!(heap.peek_min(), Some((&"far", &1)));
+        assert_eq!(heap.pop_min(), Some(("far", 1)));
+        assert!(!heap.contains(&"far"));
+        assert_eq!(heap.priority_of(&"far"), None);
+    }
+
+    #[test]
+    fn test_no_duplicates() {
+        let mut heap = IndexedHeap::new();
+        assert!(heap.push((0, 0), 8));
+        assert!(!heap.push((0, 0), 9));
+        assert!(heap.push((0, 0), 2));
+        assert_eq!(heap.len(), 1);
+        assert_eq!(heap.pop_min(), Some(((0, 0), 2)));
+        assert_eq!(heap.pop_min(), None);
+    }
+
+    #[test]
+    fn test_matches_sorting() {
+        let mut rng = Rng::new(12345);
+        let mut heap = IndexedHeap::new();
+        let mut expected = HashMap::new();
+        for _ in 0..2000 {
+            let item = rng.below(300);
+            let priority = rng.below(1000);
+            heap.push(item, priority);
+            let best = expected.entry(item).or_insert(priority);
+            *best = (*best).min(priority);
+        }
+
+        let mut expected: Vec<(usize, usize)> = expected.into_iter().map(|(i, p)| (p, i)).collect();
+        expected.sort();
+        let mut last = 0;
+        let mut popped = 0;
+        while let Some((item, priority)) = heap.pop_min() {
+            assert!(priority >= last);
+            assert!(expected.binary_search(&(priority, item)).is_ok());
+            last = priority;
+            popped += 1;
+        }
+        assert_eq!(popped, expected.len());
+    }
+}
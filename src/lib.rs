@@ -1,16 +1,96 @@
-use std::{char, fs::read_to_string};
+use std::{
+    char, env,
+    fs::read_to_string,
+    io,
+    path::{Path, PathBuf},
+};
 
-use regex::Regex;
+use crate::error::{Error, ParseError, ParseErrorKind};
 
-pub fn read_input(day: i32) -> String {
-    let path = format!("inputs/day{}.txt", day);
-    let content = read_to_string(path).unwrap();
-    return content;
+pub mod days;
+pub mod error;
+pub mod generate;
+pub mod graph;
+pub mod grid;
+pub mod heap;
+pub mod pathfind;
+pub mod point;
+pub mod solution;
+pub mod union_find;
+
+pub use grid::Grid;
+pub use point::{Direction, Point};
+
+/**
+ * Environment variable pointing at the directory holding the dayN.txt inputs.
+ */
+pub const INPUT_DIR_VAR: &str = "AOC24_INPUT_DIR";
+
+/**
+ * Reads the input for `day`, searching the default input directories.
+ */
+pub fn read_input(day: i32) -> Result<String, Error> {
+    read_input_from(&input_dirs(None), day)
+}
+
+/**
+ * Where to look for inputs, in order. An explicit directory (e.g. from the command line) wins,
+ * then $AOC24_INPUT_DIR. Otherwise try ./inputs, then the inputs folder next to Cargo.toml so
+ * running from another directory still works.
+ */
+pub fn input_dirs(explicit: Option<&Path>) -> Vec<PathBuf> {
+    if let Some(dir) = explicit {
+        return vec![dir.to_path_buf()];
+    }
+    if let Some(dir) = env::var_os(INPUT_DIR_VAR) {
+        return vec![PathBuf::from(dir)];
+    }
+    let mut dirs = vec![PathBuf::from("inputs")];
+    let manifest_inputs = Path::new(env!("CARGO_MANIFEST_DIR")).join("inputs");
+    if !dirs.contains(&manifest_inputs) {
+        dirs.push(manifest_inputs);
+    }
+    dirs
+}
+
+/**
+ * Reads dayN.txt from the first of `dirs` that has it, normalized with `normalize_input`.
+ */
+pub fn read_input_from(dirs: &[PathBuf], day: i32) -> Result<String, Error> {
+    if !(1..=25).contains(&day) {
+        return Err(Error::WrongDay(day));
+    }
+
+    let file_name = format!("day{}.txt", day);
+    let mut searched = Vec::new();
+    for dir in dirs {
+        let path = dir.join(&file_name);
+        match read_to_string(&path) {
+            Ok(content) => {
+                let content = normalize_input(&content);
+                if content.is_empty() {
+                    return Err(Error::EmptyInput { path });
+                }
+                return Ok(content);
+            }
+            Err(e) if e.kind() == io::ErrorKind::NotFound => searched.push(path),
+            Err(source) => return Err(Error::Unreadable { path, source }),
+        }
+    }
+    Err(Error::MissingInput { day, searched })
+}
+
+/**
+ * Converts CRLF line endings to LF and strips trailing newlines, so inputs saved on any platform
+ * parse the same.
+ */
+pub fn normalize_input(raw: &str) -> String {
+    raw.replace("\r\n", "\n").trim_end_matches('\n').to_string()
 }
 
 pub fn split_sections(input: &str) -> Vec<&str> {
     let sections: Vec<&str> = input.split("\n\n").filter(|&x| !x.is_empty()).collect();
-    return sections;
+    sections
 }
 
 pub fn parse_row_major<T: std::str::FromStr + std::default::Default>(
@@ -29,7 +109,7 @@ pub fn parse_row_major<T: std::str::FromStr + std::default::Default>(
             );
         }
     }
-    return rows;
+    rows
 }
 
 /**
@@ -40,31 +120,202 @@ pub fn parse_string_array(input: &str) -> Vec<Vec<char>> {
     let mut rows: Vec<Vec<char>> = Vec::new();
     for line in lines {
         let chars: Vec<char> = line.chars().collect();
-        if chars.len() > 0 {
+        if !chars.is_empty() {
             rows.push(chars);
         }
     }
-    return rows;
+    rows
+}
+
+/**
+ * Pulls exactly `N` integers out of one line, whatever is between them: `5,4`, `p=0,4 v=3,-3`
+ * and `X+94, Y+34` all work. A `-` straight before a digit is a minus sign, unless it comes
+ * right after another digit: `3-4` is a range, so 3 and 4. Anything else that isn't a digit is a
+ * separator. Doesn't allocate unless there's an error. `line_no` is only for the error.
+ */
+pub fn parse_ints<T, const N: usize>(line: &str, line_no: usize) -> Result<[T; N], ParseError>
+where
+    T: TryFrom<i64> + Copy + Default,
+{
+    let error = |column: usize, kind: ParseErrorKind| ParseError {
+        line: line_no,
+        column: column + 1,
+        text: line.to_string(),
+        kind,
+    };
+    let bytes = line.as_bytes();
+    let mut out = [T::default(); N];
+    let mut found = 0;
+    let mut i = 0;
+    while i < bytes.len() {
+        let negative = bytes[i] == b'-'
+            && bytes.get(i + 1).is_some_and(u8::is_ascii_digit)
+            && (i == 0 || !bytes[i - 1].is_ascii_digit());
+        if !negative && !bytes[i].is_ascii_digit() {
+            i += 1;
+            continue;
+        }
+        if found == N {
+            return Err(error(i, ParseErrorKind::TooMany { wanted: N }));
+        }
+
+        // build it up negated, so i64::MIN fits
+        let start = i;
+        i += negative as usize;
+        let mut value: Option<i64> = Some(0);
+        while i < bytes.len() && bytes[i].is_ascii_digit() {
+            let digit = (bytes[i] - b'0') as i64;
+            value = value.and_then(|v| v.checked_mul(10)?.checked_sub(digit));
+            i += 1;
+        }
+        let value = match negative {
+            true => value,
+            false => value.and_then(i64::checked_neg),
+        };
+        out[found] = value
+            .and_then(|v| T::try_from(v).ok())
+            .ok_or_else(|| error(start, ParseErrorKind::OutOfRange))?;
+        found += 1;
+    }
+    if found < N {
+        return Err(error(
+            bytes.len(),
+            ParseErrorKind::TooFew { wanted: N, found },
+        ));
+    }
+    Ok(out)
 }
 
 /**
- * Adds a i32 delta to a usize tuple. Useful for grid traversal.
+ * `parse_ints` on every line that isn't blank.
  */
-pub fn uadd_idirection(coord: (usize, usize), direction: (i32, i32)) -> (usize, usize) {
-    (
-        (coord.0 as i32 + direction.0) as usize,
-        (coord.1 as i32 + direction.1) as usize,
-    )
+pub fn parse_int_lines<T, const N: usize>(input: &str) -> Result<Vec<[T; N]>, ParseError>
+where
+    T: TryFrom<i64> + Copy + Default,
+{
+    input
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty())
+        .map(|(i, line)| parse_ints(line, i + 1))
+        .collect()
 }
 
-fn parse_coord(line: &str) -> (i32, i32) {
-    let re = Regex::new(r"(?P<x>-?\d+),(?P<y>-?\d+)").unwrap();
-    let caps = re.captures(line).unwrap();
-    let x = caps.name("x").unwrap().as_str().parse::<i32>().unwrap();
-    let y = caps.name("y").unwrap().as_str().parse::<i32>().unwrap();
-    (y, x)
+/**
+ * One `x,y` pair per line, as `(y, x)` so they index a `Grid` the right way round.
+ */
+pub fn parse_coords(input: &str) -> Result<Vec<(i32, i32)>, ParseError> {
+    let pairs = parse_int_lines::<i32, 2>(input)?;
+    Ok(pairs.into_iter().map(|[x, y]| (y, x)).collect())
 }
 
-pub fn parse_coords(input: &str) -> Vec<(i32, i32)> {
-    input.lines().map(|l| parse_coord(l)).collect()
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use super::*;
+
+    fn scratch_dir(name: &str) -> PathBuf {
+        let dir = env::temp_dir().join(format!("aoc24-{}-{}", name, std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    #[test]
+    fn test_parse_ints() {
+        assert_eq!(parse_ints::<i32, 2>("5,4", 1), Ok([5, 4]));
+        assert_eq!(parse_ints::<i32, 4>("p=0,4 v=3,-3", 1), Ok([0, 4, 3, -3]));
+        assert_eq!(
+            parse_ints::<i64, 2>("Button A: X+94, Y+34", 1),
+            Ok([94, 34])
+        );
+        assert_eq!(parse_ints::<i32, 3>("-1 - -2;x3", 1), Ok([-1, -2, 3]));
+        assert_eq!(parse_ints::<i32, 2>("3-4", 1), Ok([3, 4]));
+        assert_eq!(parse_ints::<i32, 3>("-3--4 -5", 1), Ok([-3, -4, -5]));
+        assert_eq!(
+            parse_ints::<i64, 2>("-9223372036854775808 9223372036854775807", 1),
+            Ok([i64::MIN, i64::MAX])
+        );
+        assert_eq!(parse_ints::<usize, 0>("no numbers here", 1), Ok([]));
+    }
+
+    #[test]
+    fn test_parse_ints_errors() {
+        let error = parse_ints::<i32, 2>("12,", 7).unwrap_err();
+        assert_eq!(error.line, 7);
+        assert_eq!(error.column, 4);
+        assert_eq!(error.text, "12,");
+        assert_eq!(
+            error.kind,
+            ParseErrorKind::TooFew {
+                wanted: 2,
+                found: 1
+            }
+        );
+        assert_eq!(
+            error.to_string(),
+            "line 7, column 4: expected 2 numbers, found 1 in \"12,\""
+        );
+
+        let error = parse_ints::<i32, 2>("1,2,3", 1).unwrap_err();
+        assert_eq!(
+            (error.column, error.kind),
+            (5, ParseErrorKind::TooMany { wanted: 2 })
+        );
+        let error = parse_ints::<usize, 2>("4,-5", 1).unwrap_err();
+        assert_eq!((error.column, error.kind), (3, ParseErrorKind::OutOfRange));
+        let error = parse_ints::<i32, 1>("x=99999999999", 1).unwrap_err();
+        assert_eq!((error.column, error.kind), (3, ParseErrorKind::OutOfRange));
+        let error = parse_ints::<i64, 1>("99999999999999999999", 1).unwrap_err();
+        assert_eq!(error.kind, ParseErrorKind::OutOfRange);
+    }
+
+    #[test]
+    fn test_parse_coords() {
+        assert_eq!(
+            parse_coords("5,4\n-3,10\n\n0,6"),
+            Ok(vec![(4, 5), (10, -3), (6, 0)])
+        );
+        let error = parse_coords("5,4\n\n6 7\n8\n").unwrap_err();
+        assert_eq!((error.line, error.text.as_str()), (4, "8"));
+    }
+
+    #[test]
+    fn test_normalize_input() {
+        assert_eq!(normalize_input("1,2\r\n3,4\r\n\r\n"), "1,2\n3,4");
+        assert_eq!(normalize_input("a\n\nb\n"), "a\n\nb");
+    }
+
+    #[test]
+    fn test_read_input_from() {
+        let dir = scratch_dir("read");
+        fs::write(dir.join("day3.txt"), "mul(2,4)\r\n").unwrap();
+        let dirs = vec![PathBuf::from("does-not-exist"), dir.clone()];
+        assert_eq!(read_input_from(&dirs, 3).unwrap(), "mul(2,4)");
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn test_read_input_errors() {
+        let dir = scratch_dir("errors");
+        fs::write(dir.join("day4.txt"), "\n").unwrap();
+        let dirs = vec![dir.clone()];
+
+        match read_input_from(&dirs, 3) {
+            Err(Error::MissingInput { day, searched }) => {
+                assert_eq!(day, 3);
+                assert_eq!(searched, vec![dir.join("day3.txt")]);
+            }
+            other => panic!("expected MissingInput, got {:?}", other),
+        }
+        assert!(matches!(
+            read_input_from(&dirs, 4),
+            Err(Error::EmptyInput { .. })
+        ));
+        assert!(matches!(
+            read_input_from(&dirs, 26),
+            Err(Error::WrongDay(26))
+        ));
+        fs::remove_dir_all(dir).unwrap();
+    }
 }
@@ -0,0 +1,126 @@
+use std::{env, path::PathBuf, process::ExitCode, time::Instant};
+
+use aoc24::days;
+use aoc24::error::Error;
+use aoc24::solution::DynSolution;
+use aoc24::*;
+
+const USAGE: &str = "usage: aoc24 run <day|all> [--part <1|2|variant>] [--inputs <dir>]
+       aoc24 list";
+
+fn main() -> ExitCode {
+    let args: Vec<String> = env::args().skip(1).collect();
+    match args.first().map(|s| s.as_str()) {
+        Some("run") => run(&args[1..]),
+        Some("list") => {
+            for solution in days::SOLUTIONS {
+                println!(
+                    "day {}: {} [{}]",
+                    solution.day(),
+                    solution.title(),
+                    solution.parts().join(", ")
+                );
+            }
+            ExitCode::SUCCESS
+        }
+        _ => {
+            eprintln!("{}", USAGE);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn run(args: &[String]) -> ExitCode {
+    let mut target = None;
+    let mut part = None;
+    let mut inputs: Option<PathBuf> = None;
+
+    let mut i = 0;
+    while i < args.len() {
+        match args[i].as_str() {
+            "--part" | "-p" => {
+                i += 1;
+                match args.get(i) {
+                    Some(p) => part = Some(p.as_str()),
+                    None => {
+                        eprintln!("--part expects 1, 2 or the name of a variant");
+                        return ExitCode::FAILURE;
+                    }
+                }
+            }
+            "--inputs" => {
+                i += 1;
+                match args.get(i) {
+                    Some(dir) => inputs = Some(PathBuf::from(dir)),
+                    None => {
+                        eprintln!("--inputs expects a directory");
+                        return ExitCode::FAILURE;
+                    }
+                }
+            }
+            arg if target.is_none() => target = Some(arg.to_string()),
+            arg => {
+                eprintln!("unexpected argument `{}`\n{}", arg, USAGE);
+                return ExitCode::FAILURE;
+            }
+        }
+        i += 1;
+    }
+
+    let selected: Vec<&dyn DynSolution> = match target.as_deref() {
+        // with a variant name, only run the days that have that variant
+        Some("all") => days::SOLUTIONS
+            .iter()
+            .filter(|s| part.is_none_or(|p| s.parts().contains(&p)))
+            .copied()
+            .collect(),
+        Some(day) => match day.parse::<i32>().ok().and_then(days::get) {
+            Some(day) => vec![day],
+            None => {
+                eprintln!("no solution registered for day `{}`", day);
+                return ExitCode::FAILURE;
+            }
+        },
+        None => {
+            eprintln!("{}", USAGE);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    if let (Some(part), [solution]) = (part, selected.as_slice()) {
+        if !solution.parts().contains(&part) {
+            eprintln!("day {} has no part `{}`", solution.day(), part);
+            return ExitCode::FAILURE;
+        }
+    }
+
+    let dirs = input_dirs(inputs.as_deref());
+    let mut status = ExitCode::SUCCESS;
+    for solution in selected {
+        let parts = match part {
+            Some(part) => vec![part],
+            None => vec!["1", "2"],
+        };
+        if let Err(e) = run_day(solution, &parts, &dirs) {
+            eprintln!("{}", e);
+            status = ExitCode::FAILURE;
+        }
+    }
+    status
+}
+
+fn run_day(solution: &dyn DynSolution, parts: &[&str], dirs: &[PathBuf]) -> Result<(), Error> {
+    let input = read_input_from(dirs, solution.day())?;
+    println!("Day {}: {}", solution.day(), solution.title());
+
+    let start = Instant::now();
+    let prepared = solution.prepare(&input)?;
+    println!("Parse: ({:.2?})", start.elapsed());
+
+    for &part in parts {
+        let start = Instant::now();
+        let answer = prepared.solve(part).unwrap();
+        println!("Part {}: {} ({:.2?})", part, answer, start.elapsed());
+    }
+    Ok(())
+}
@@ -0,0 +1,540 @@
+use std::{
+    collections::{HashMap, HashSet},
+    hash::Hash,
+};
+
+use crate::graph::DiGraph;
+use crate::heap::IndexedHeap;
+
+/**
+ * Result of a shortest-path search: distances to every settled node, and for each of them every
+ * predecessor that lies on an optimal path. Following predecessors back from a node walks the
+ * DAG of all its shortest paths.
+ *
+ * Zero-cost edges are fine for distances, but a predecessor reached through one can be settled
+ * too late to be recorded, so counts and DAGs assume positive costs.
+ */
+#[derive(Debug, Clone)]
+pub struct ShortestPaths<N> {
+    dist: HashMap<N, usize>,
+    preds: HashMap<N, Vec<N>>,
+    // settled nodes by non-decreasing distance, so predecessors always come first
+    order: Vec<N>,
+    goals: Vec<N>,
+}
+
+/**
+ * Dijkstra from `starts` over everything reachable. `neighbours` yields (node, edge cost) pairs.
+ */
+pub fn dijkstra<N, F, I>(starts: impl IntoIterator<Item = N>, neighbours: F) -> ShortestPaths<N>
+where
+    N: Eq + Hash + Clone,
+    F: FnMut(&N) -> I,
+    I: IntoIterator<Item = (N, usize)>,
+{
+    search(starts, neighbours, |_| 0, |_| false)
+}
+
+/**
+ * Dijkstra that stops once every goal at the cheapest goal distance is settled. Nodes further
+ * away than that are left out of the result.
+ */
+pub fn dijkstra_to<N, F, I>(
+    starts: impl IntoIterator<Item = N>,
+    neighbours: F,
+    is_goal: impl Fn(&N) -> bool,
+) -> ShortestPaths<N>
+where
+    N: Eq + Hash + Clone,
+    F: FnMut(&N) -> I,
+    I: IntoIterator<Item = (N, usize)>,
+{
+    search(starts, neighbours, |_| 0, is_goal)
+}
+
+/**
+ * A* towards the nodes matching `is_goal`. The heuristic must be consistent (never drop by more
+ * than the edge cost, and 0 at goals) for the distances and the predecessor DAG to be exact.
+ */
+pub fn astar<N, F, I>(
+    starts: impl IntoIterator<Item = N>,
+    neighbours: F,
+    heuristic: impl Fn(&N) -> usize,
+    is_goal: impl Fn(&N) -> bool,
+) -> ShortestPaths<N>
+where
+    N: Eq + Hash + Clone,
+    F: FnMut(&N) -> I,
+    I: IntoIterator<Item = (N, usize)>,
+{
+    search(starts, neighbours, heuristic, is_goal)
+}
+
+fn search<N, F, I>(
+    starts: impl IntoIterator<Item = N>,
+    neighbours: F,
+    heuristic: impl Fn(&N) -> usize,
+    is_goal: impl Fn(&N) -> bool,
+) -> ShortestPaths<N>
+where
+    N: Eq + Hash + Clone,
+    F: FnMut(&N) -> I,
+    I: IntoIterator<Item = (N, usize)>,
+{
+    let mut frontier = Frontier::new(starts, neighbours, heuristic);
+    let mut goals = Vec::new();
+    let mut best_goal: Option<usize> = None;
+
+    while let Some((priority, _)) = frontier.peek() {
+        if best_goal.is_some_and(|best| priority > best) {
+            break;
+        }
+        let (node, node_dist, _) = frontier.settle_next().unwrap();
+        if is_goal(&node) {
+            best_goal = Some(node_dist);
+            goals.push(node);
+        }
+    }
+    frontier.finish(goals)
+}
+
+// a settled node, its distance and the edges out of it
+type Settled<N> = (N, usize, Vec<(N, usize)>);
+
+/**
+ * A search in progress, settled one node at a time so that two can take turns.
+ */
+struct Frontier<N, F, H> {
+    dist: HashMap<N, usize>,
+    preds: HashMap<N, Vec<N>>,
+    settled: HashSet<N>,
+    order: Vec<N>,
+    // Ties on priority go to the nearer node, so with A* every predecessor is settled before the
+    // nodes it leads to.
+    queue: IndexedHeap<N, (usize, usize)>,
+    neighbours: F,
+    heuristic: H,
+}
+
+impl<N, F, I, H> Frontier<N, F, H>
+where
+    N: Eq + Hash + Clone,
+    F: FnMut(&N) -> I,
+    I: IntoIterator<Item = (N, usize)>,
+    H: Fn(&N) -> usize,
+{
+    fn new(starts: impl IntoIterator<Item = N>, neighbours: F, heuristic: H) -> Self {
+        let mut frontier = Frontier {
+            dist: HashMap::new(),
+            preds: HashMap::new(),
+            settled: HashSet::new(),
+            order: Vec::new(),
+            queue: IndexedHeap::new(),
+            neighbours,
+            heuristic,
+        };
+        for start in starts {
+            frontier.dist.insert(start.clone(), 0);
+            frontier.preds.insert(start.clone(), Vec::new());
+            let priority = (frontier.heuristic)(&start);
+            frontier.queue.push(start, (priority, 0));
+        }
+        frontier
+    }
+
+    /**
+     * Priority and distance of the next node to be settled.
+     */
+    fn peek(&self) -> Option<(usize, usize)> {
+        self.queue.peek_min().map(|(_, &key)| key)
+    }
+
+    fn is_settled(&self, node: &N) -> bool {
+        self.settled.contains(node)
+    }
+
+    /**
+     * Settles the nearest queued node and queues its neighbours. Returns it, its distance and
+     * the edges out of it.
+     */
+    fn settle_next(&mut self) -> Option<Settled<N>> {
+        let (node, (_, node_dist)) = self.queue.pop_min()?;
+        self.settled.insert(node.clone());
+        self.order.push(node.clone());
+
+        let edges: Vec<(N, usize)> = (self.neighbours)(&node).into_iter().collect();
+        for (next, cost) in &edges {
+            if self.settled.contains(next) {
+                continue;
+            }
+            let next_dist = node_dist + cost;
+            match self.dist.get(next) {
+                Some(&d) if d < next_dist => continue,
+                Some(&d) if d == next_dist => {
+                    self.preds.get_mut(next).unwrap().push(node.clone());
+                    continue;
+                }
+                _ => {}
+            }
+            self.dist.insert(next.clone(), next_dist);
+            self.preds.insert(next.clone(), vec![node.clone()]);
+            let priority = next_dist + (self.heuristic)(next);
+            self.queue.push(next.clone(), (priority, next_dist));
+        }
+        Some((node, node_dist, edges))
+    }
+
+    fn finish(mut self, goals: Vec<N>) -> ShortestPaths<N> {
+        // anything still queued only has a tentative distance
+        let settled = &self.settled;
+        self.dist.retain(|node, _| settled.contains(node));
+        self.preds.retain(|node, _| settled.contains(node));
+
+        ShortestPaths {
+            dist: self.dist,
+            preds: self.preds,
+            order: self.order,
+            goals,
+        }
+    }
+}
+
+/**
+ * Result of a bidirectional search: a search out from the starts, one back from the goals, and
+ * where they meet on the cheapest paths.
+ */
+#[derive(Debug, Clone)]
+pub struct Bidirectional<N> {
+    pub forward: ShortestPaths<N>,
+    pub backward: ShortestPaths<N>,
+    best: Option<usize>,
+    // the last node settled from the start and the first from the goal on each cheapest path
+    meetings: Vec<(N, N)>,
+}
+
+/**
+ * Dijkstra from `starts` and, taking turns, back from `goals` until the two searches have
+ * between them covered every cheapest path. `predecessors` is `neighbours` backwards: the nodes
+ * with an edge to the given one, with the edge's cost.
+ *
+ * Costs must be positive for the cheapest paths to all be found.
+ */
+pub fn bidirectional<N, F, I, B, J>(
+    starts: impl IntoIterator<Item = N>,
+    goals: impl IntoIterator<Item = N>,
+    neighbours: F,
+    predecessors: B,
+) -> Bidirectional<N>
+where
+    N: Eq + Hash + Clone,
+    F: FnMut(&N) -> I,
+    I: IntoIterator<Item = (N, usize)>,
+    B: FnMut(&N) -> J,
+    J: IntoIterator<Item = (N, usize)>,
+{
+    let mut forward = Frontier::new(starts, neighbours, |_: &N| 0);
+    let mut backward = Frontier::new(goals, predecessors, |_: &N| 0);
+    let mut best: Option<usize> = None;
+    let mut meetings: Vec<(N, N, usize)> = Vec::new();
+
+    loop {
+        // an exhausted side has nothing further away to find
+        let next_forward = forward.peek().map_or(usize::MAX, |(d, _)| d);
+        let next_backward = backward.peek().map_or(usize::MAX, |(d, _)| d);
+        if next_forward == usize::MAX && next_backward == usize::MAX {
+            break;
+        }
+        // Any path costing no more than `best` that hasn't been seen would need a node at least
+        // `next_forward` from the start and `next_backward` from the goal. Only stopping once
+        // that's strictly more than `best` means ties are all found too.
+        if best.is_some_and(|best| next_forward.saturating_add(next_backward) > best) {
+            break;
+        }
+
+        let found = meetings.len();
+        if next_forward <= next_backward {
+            let (node, dist, edges) = forward.settle_next().unwrap();
+            if backward.is_settled(&node) {
+                meetings.push((node.clone(), node.clone(), dist + backward.dist[&node]));
+            }
+            for (next, cost) in edges {
+                if backward.is_settled(&next) {
+                    meetings.push((
+                        node.clone(),
+                        next.clone(),
+                        dist + cost + backward.dist[&next],
+                    ));
+                }
+            }
+        } else {
+            let (node, dist, edges) = backward.settle_next().unwrap();
+            if forward.is_settled(&node) {
+                meetings.push((node.clone(), node.clone(), forward.dist[&node] + dist));
+            }
+            for (prev, cost) in edges {
+                if forward.is_settled(&prev) {
+                    meetings.push((
+                        prev.clone(),
+                        node.clone(),
+                        forward.dist[&prev] + cost + dist,
+                    ));
+                }
+            }
+        }
+        for &(_, _, cost) in &meetings[found..] {
+            best = Some(best.map_or(cost, |best| best.min(cost)));
+        }
+    }
+
+    let meetings = meetings
+        .into_iter()
+        .filter(|&(_, _, cost)| Some(cost) == best)
+        .map(|(from, to, _)| (from, to))
+        .collect();
+    Bidirectional {
+        forward: forward.finish(Vec::new()),
+        backward: backward.finish(Vec::new()),
+        best,
+        meetings,
+    }
+}
+
+impl<N: Eq + Hash + Clone> Bidirectional<N> {
+    /**
+     * Cost of the cheapest path from a start to a goal.
+     */
+    pub fn distance(&self) -> Option<usize> {
+        self.best
+    }
+
+    /**
+     * Nodes settled by either search.
+     */
+    pub fn expanded(&self) -> usize {
+        self.forward.settled().len() + self.backward.settled().len()
+    }
+
+    /**
+     * Every node on at least one cheapest path from a start to a goal.
+     */
+    pub fn on_optimal_paths(&self) -> HashSet<N> {
+        let (from, to): (Vec<N>, Vec<N>) = self.meetings.iter().cloned().unzip();
+        let mut nodes = self.forward.on_optimal_paths(&from);
+        nodes.extend(self.backward.on_optimal_paths(&to));
+        nodes
+    }
+}
+
+impl<N: Eq + Hash + Clone> ShortestPaths<N> {
+    pub fn distance(&self, node: &N) -> Option<usize> {
+        self.dist.get(node).copied()
+    }
+
+    /**
+     * Every node right before `node` on some shortest path to it. Empty for starts and for nodes
+     * the search never settled.
+     */
+    pub fn predecessors(&self, node: &N) -> &[N] {
+        self.preds.get(node).map_or(&[], |p| p.as_slice())
+    }
+
+    /**
+     * Settled nodes, nearest first.
+     */
+    pub fn settled(&self) -> &[N] {
+        &self.order
+    }
+
+    /**
+     * The goals reached at the cheapest goal distance, in the order they were settled. Always
+     * empty for a plain `dijkstra`.
+     */
+    pub fn goals(&self) -> &[N] {
+        &self.goals
+    }
+
+    pub fn goal_distance(&self) -> Option<usize> {
+        self.goals.first().and_then(|goal| self.distance(goal))
+    }
+
+    /**
+     * One shortest path from a start to `node`, both ends included.
+     */
+    pub fn path_to(&self, node: &N) -> Option<Vec<N>> {
+        self.distance(node)?;
+        let mut path = vec![node.clone()];
+        let mut cur = node;
+        while let Some(prev) = self.predecessors(cur).first() {
+            path.push(prev.clone());
+            cur = prev;
+        }
+        path.reverse();
+        Some(path)
+    }
+
+    /**
+     * How many distinct shortest paths lead to each settled node.
+     */
+    pub fn path_counts(&self) -> HashMap<N, usize> {
+        let mut counts: HashMap<N, usize> = HashMap::new();
+        for node in &self.order {
+            let preds = self.predecessors(node);
+            let count = if preds.is_empty() {
+                1
+            } else {
+                preds.iter().map(|p| counts[p]).sum()
+            };
+            counts.insert(node.clone(), count);
+        }
+        counts
+    }
+
+    /**
+     * Number of distinct shortest paths to `target`, 0 if it wasn't reached.
+     */
+    pub fn count_paths(&self, target: &N) -> usize {
+        if self.distance(target).is_none() {
+            return 0;
+        }
+        self.path_counts()[target]
+    }
+
+    /**
+     * The predecessor DAG as a graph, with edges pointing away from the starts.
+     */
+    pub fn dag(&self) -> DiGraph<N> {
+        let mut dag = DiGraph::new();
+        for node in &self.order {
+            dag.add_node(node.clone());
+            for pred in self.predecessors(node) {
+                dag.add_edge(pred.clone(), node.clone());
+            }
+        }
+        dag
+    }
+
+    /**
+     * Every node on at least one shortest path to any of `targets`.
+     */
+    pub fn on_optimal_paths<'a>(&self, targets: impl IntoIterator<Item = &'a N>) -> HashSet<N>
+    where
+        N: 'a,
+    {
+        self.dag().reaching(targets)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Grid;
+
+    /**
+     * Two equally short routes 0 -> 1 -> 3 and 0 -> 2 -> 3, a longer one through 4.
+     */
+    fn diamond(node: &usize) -> Vec<(usize, usize)> {
+        match node {
+            0 => vec![(1, 1), (2, 1), (4, 1)],
+            1 => vec![(3, 1)],
+            2 => vec![(3, 1)],
+            4 => vec![(3, 5)],
+            _ => vec![],
+        }
+    }
+
+    #[test]
+    fn test_dijkstra() {
+        let paths = dijkstra([0], diamond);
+        assert_eq!(paths.distance(&3), Some(2));
+        assert_eq!(paths.distance(&4), Some(1));
+        assert_eq!(paths.distance(&5), None);
+        let mut preds = paths.predecessors(&3).to_vec();
+        preds.sort();
+        assert_eq!(preds, vec![1, 2]);
+        assert_eq!(paths.count_paths(&3), 2);
+        assert_eq!(paths.path_to(&3).unwrap().len(), 3);
+        assert_eq!(paths.on_optimal_paths(&[3]), HashSet::from([0, 1, 2, 3]));
+        assert!(paths.goals().is_empty());
+    }
+
+    #[test]
+    fn test_dijkstra_to() {
+        let paths = dijkstra_to([0], diamond, |&n| n == 1 || n == 2);
+        assert_eq!(paths.goal_distance(), Some(1));
+        let mut goals = paths.goals().to_vec();
+        goals.sort();
+        // both goals sit at the same distance, so both are reported
+        assert_eq!(goals, vec![1, 2]);
+        assert_eq!(paths.distance(&3), None);
+    }
+
+    #[test]
+    fn test_zero_cost_edges() {
+        let paths = dijkstra([0], |&n: &usize| match n {
+            0 => vec![(1, 0)],
+            1 => vec![(0, 0), (2, 3)],
+            _ => vec![],
+        });
+        assert_eq!(paths.distance(&1), Some(0));
+        assert_eq!(paths.count_paths(&2), 1);
+    }
+
+    #[test]
+    fn test_bidirectional() {
+        let backwards = |node: &usize| -> Vec<(usize, usize)> {
+            (0..5)
+                .flat_map(|from| {
+                    diamond(&from)
+                        .into_iter()
+                        .map(move |(to, cost)| (from, to, cost))
+                })
+                .filter(|&(_, to, _)| to == *node)
+                .map(|(from, _, cost)| (from, cost))
+                .collect()
+        };
+        let search = bidirectional([0], [3], diamond, backwards);
+        assert_eq!(search.distance(), Some(2));
+        assert_eq!(search.on_optimal_paths(), HashSet::from([0, 1, 2, 3]));
+
+        let search = bidirectional([0], [5], diamond, backwards);
+        assert_eq!(search.distance(), None);
+        assert!(search.on_optimal_paths().is_empty());
+    }
+
+    #[test]
+    fn test_astar_matches_dijkstra() {
+        let grid = Grid::parse(
+            "\
+.....#....
+.###.#.##.
+...#...#..
+##.#####.#
+...#......
+.#...###..",
+        );
+        let end = (grid.height() - 1, grid.width() - 1);
+        let open = |pos: &(usize, usize)| -> Vec<((usize, usize), usize)> {
+            grid.neighbours4(*pos)
+                .filter(|&n| grid[n] != '#')
+                .map(|n| (n, 1))
+                .collect()
+        };
+        let manhattan = |pos: &(usize, usize)| end.0.abs_diff(pos.0) + end.1.abs_diff(pos.1);
+
+        let plain = dijkstra_to([(0, 0)], open, |&p| p == end);
+        let guided = astar([(0, 0)], open, manhattan, |&p| p == end);
+        assert_eq!(plain.goal_distance(), guided.goal_distance());
+        assert_eq!(plain.count_paths(&end), guided.count_paths(&end));
+        assert_eq!(
+            plain.on_optimal_paths(&[end]),
+            guided.on_optimal_paths(&[end])
+        );
+        assert!(guided.settled().len() <= plain.settled().len());
+
+        // the grid's edges all go both ways, so it is its own reverse
+        let both = bidirectional([(0, 0)], [end], open, open);
+        assert_eq!(both.distance(), plain.goal_distance());
+        assert_eq!(both.on_optimal_paths(), plain.on_optimal_paths(&[end]));
+        assert!(both.expanded() <= plain.settled().len() + 1);
+    }
+}
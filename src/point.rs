@@ -0,0 +1,276 @@
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+
+/**
+ * A signed (row, column) position or offset. Rows grow downwards, like grid indices.
+ */
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+pub struct Point {
+    pub row: i32,
+    pub col: i32,
+}
+
+impl Point {
+    pub const ZERO: Point = Point { row: 0, col: 0 };
+
+    pub const fn new(row: i32, col: i32) -> Self {
+        Point { row, col }
+    }
+
+    /**
+     * Back to grid indices, or None if either coordinate is negative.
+     */
+    pub fn to_usize(self) -> Option<(usize, usize)> {
+        Some((self.row.try_into().ok()?, self.col.try_into().ok()?))
+    }
+
+    /**
+     * Moves the unsigned `coord` by this offset. None instead of wrapping when it would go
+     * negative.
+     */
+    pub fn add_to(self, coord: (usize, usize)) -> Option<(usize, usize)> {
+        Some((
+            coord.0.checked_add_signed(self.row as isize)?,
+            coord.1.checked_add_signed(self.col as isize)?,
+        ))
+    }
+
+    pub fn manhattan(self, other: Point) -> i32 {
+        (self.row - other.row).abs() + (self.col - other.col).abs()
+    }
+}
+
+impl From<(i32, i32)> for Point {
+    fn from((row, col): (i32, i32)) -> Self {
+        Point { row, col }
+    }
+}
+
+impl From<Point> for (i32, i32) {
+    fn from(p: Point) -> Self {
+        (p.row, p.col)
+    }
+}
+
+/**
+ * Panics if the coordinate doesn't fit in an i32, which no puzzle grid comes close to.
+ */
+impl From<(usize, usize)> for Point {
+    fn from((row, col): (usize, usize)) -> Self {
+        Point {
+            row: row.try_into().unwrap(),
+            col: col.try_into().unwrap(),
+        }
+    }
+}
+
+impl From<Direction> for Point {
+    fn from(direction: Direction) -> Self {
+        direction.delta()
+    }
+}
+
+impl Add for Point {
+    type Output = Point;
+
+    fn add(self, rhs: Point) -> Point {
+        Point::new(self.row + rhs.row, self.col + rhs.col)
+    }
+}
+
+impl Add<Direction> for Point {
+    type Output = Point;
+
+    fn add(self, rhs: Direction) -> Point {
+        self + rhs.delta()
+    }
+}
+
+impl AddAssign for Point {
+    fn add_assign(&mut self, rhs: Point) {
+        *self = *self + rhs;
+    }
+}
+
+impl AddAssign<Direction> for Point {
+    fn add_assign(&mut self, rhs: Direction) {
+        *self = *self + rhs;
+    }
+}
+
+impl Sub for Point {
+    type Output = Point;
+
+    fn sub(self, rhs: Point) -> Point {
+        Point::new(self.row - rhs.row, self.col - rhs.col)
+    }
+}
+
+impl SubAssign for Point {
+    fn sub_assign(&mut self, rhs: Point) {
+        *self = *self - rhs;
+    }
+}
+
+impl Neg for Point {
+    type Output = Point;
+
+    fn neg(self) -> Point {
+        Point::new(-self.row, -self.col)
+    }
+}
+
+impl Mul<i32> for Point {
+    type Output = Point;
+
+    fn mul(self, rhs: i32) -> Point {
+        Point::new(self.row * rhs, self.col * rhs)
+    }
+}
+
+/**
+ * The four grid directions. Declared in the "down, right, up, left" order the days have always
+ * used for their DELTAS arrays, so `index` matches the old direction numbers.
+ */
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Direction {
+    Down,
+    Right,
+    Up,
+    Left,
+}
+
+impl Direction {
+    pub const ALL: [Direction; 4] = [
+        Direction::Down,
+        Direction::Right,
+        Direction::Up,
+        Direction::Left,
+    ];
+
+    pub fn index(self) -> usize {
+        self as usize
+    }
+
+    /**
+     * Inverse of `index`, wrapping around past 3.
+     */
+    pub fn from_index(index: usize) -> Self {
+        Self::ALL[index % 4]
+    }
+
+    pub fn delta(self) -> Point {
+        match self {
+            Direction::Down => Point::new(1, 0),
+            Direction::Right => Point::new(0, 1),
+            Direction::Up => Point::new(-1, 0),
+            Direction::Left => Point::new(0, -1),
+        }
+    }
+
+    /**
+     * Counterclockwise on screen: facing down, a left turn faces right.
+     */
+    pub fn turn_left(self) -> Self {
+        Self::from_index(self.index() + 1)
+    }
+
+    pub fn turn_right(self) -> Self {
+        Self::from_index(self.index() + 3)
+    }
+
+    pub fn reverse(self) -> Self {
+        Self::from_index(self.index() + 2)
+    }
+
+    pub fn is_vertical(self) -> bool {
+        matches!(self, Direction::Down | Direction::Up)
+    }
+
+    /**
+     * Moves the unsigned `coord` one step, or None if that would go negative.
+     */
+    pub fn step(self, coord: (usize, usize)) -> Option<(usize, usize)> {
+        self.delta().add_to(coord)
+    }
+
+    /**
+     * Parses the `^`, `v`, `<`, `>` arrows used in puzzle inputs.
+     */
+    pub fn from_arrow(c: char) -> Option<Self> {
+        match c {
+            'v' => Some(Direction::Down),
+            '>' => Some(Direction::Right),
+            '^' => Some(Direction::Up),
+            '<' => Some(Direction::Left),
+            _ => None,
+        }
+    }
+
+    pub fn arrow(self) -> char {
+        match self {
+            Direction::Down => 'v',
+            Direction::Right => '>',
+            Direction::Up => '^',
+            Direction::Left => '<',
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_arithmetic() {
+        let a = Point::new(3, -2);
+        let b = Point::from((1, 4));
+        assert_eq!(a + b, Point::new(4, 2));
+        assert_eq!(a - b, Point::new(2, -6));
+        assert_eq!(-a, Point::new(-3, 2));
+        assert_eq!(b * 3, Point::new(3, 12));
+        assert_eq!(a + Direction::Up, Point::new(2, -2));
+        assert_eq!(a.manhattan(b), 8);
+    }
+
+    #[test]
+    fn test_unsigned() {
+        assert_eq!(Point::new(2, 0).to_usize(), Some((2, 0)));
+        assert_eq!(Point::new(2, -1).to_usize(), None);
+        assert_eq!(Point::from((5usize, 7usize)), Point::new(5, 7));
+        assert_eq!(Direction::Left.step((3, 0)), None);
+        assert_eq!(Direction::Up.step((3, 0)), Some((2, 0)));
+        assert_eq!(Point::new(-1, 2).add_to((1, 1)), Some((0, 3)));
+    }
+
+    #[test]
+    fn test_turns() {
+        for d in Direction::ALL {
+            assert_eq!(d.turn_left().turn_right(), d);
+            assert_eq!(d.turn_left().turn_left(), d.reverse());
+            assert_eq!(d.delta() + d.reverse().delta(), Point::ZERO);
+            assert_eq!(Direction::from_index(d.index()), d);
+        }
+        assert_eq!(Direction::Up.turn_right(), Direction::Right);
+        assert_eq!(Direction::Down.turn_left(), Direction::Right);
+    }
+
+    #[test]
+    fn test_arrows() {
+        let parsed: Vec<Direction> = "<^^>\nv"
+            .chars()
+            .filter_map(Direction::from_arrow)
+            .collect();
+        assert_eq!(
+            parsed,
+            vec![
+                Direction::Left,
+                Direction::Up,
+                Direction::Up,
+                Direction::Right,
+                Direction::Down
+            ]
+        );
+        let arrows: String = parsed.iter().map(|d| d.arrow()).collect();
+        assert_eq!(arrows, "<^^>v");
+    }
+}
@@ -0,0 +1,113 @@
+use crate::error::Error;
+
+/**
+ * A single day's puzzle. Input is parsed once, then handed to each part.
+ */
+pub trait Solution {
+    type Input;
+
+    fn day(&self) -> i32;
+
+    fn title(&self) -> &'static str;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, Error>;
+
+    fn part1(&self, input: &Self::Input) -> String;
+
+    fn part2(&self, input: &Self::Input) -> String;
+
+    /**
+     * Alternative ways of solving a part (a faster version, a debugging view, etc.)
+     */
+    fn variants(&self) -> Vec<Variant<Self::Input>> {
+        Vec::new()
+    }
+}
+
+pub struct Variant<I> {
+    pub name: &'static str,
+    pub run: fn(&I) -> String,
+}
+
+impl<I> Variant<I> {
+    pub fn new(name: &'static str, run: fn(&I) -> String) -> Self {
+        Variant { name, run }
+    }
+}
+
+/**
+ * Object-safe view of a `Solution`, so days with different input types can share a registry.
+ * Every `Solution` gets this for free.
+ */
+pub trait DynSolution: Sync {
+    fn day(&self) -> i32;
+
+    fn title(&self) -> &'static str;
+
+    /**
+     * Names accepted by `Prepared::solve`: "1", "2", then any variants.
+     */
+    fn parts(&self) -> Vec<&'static str>;
+
+    /**
+     * Parses the input once for all the parts, or says what's wrong with it.
+     */
+    fn prepare<'a>(&'a self, input: &str) -> Result<Box<dyn Prepared + 'a>, Error>;
+}
+
+/**
+ * A solution together with its already-parsed input.
+ */
+pub trait Prepared {
+    /**
+     * Returns None if `part` isn't one of the solution's parts.
+     */
+    fn solve(&self, part: &str) -> Option<String>;
+}
+
+struct Parsed<'a, S: Solution> {
+    solution: &'a S,
+    input: S::Input,
+}
+
+impl<S: Solution> Prepared for Parsed<'_, S> {
+    fn solve(&self, part: &str) -> Option<String> {
+        match part {
+            "1" => Some(self.solution.part1(&self.input)),
+            "2" => Some(self.solution.part2(&self.input)),
+            _ => self
+                .solution
+                .variants()
+                .iter()
+                .find(|v| v.name == part)
+                .map(|v| (v.run)(&self.input)),
+        }
+    }
+}
+
+impl<S> DynSolution for S
+where
+    S: Solution + Sync,
+    S::Input: 'static,
+{
+    fn day(&self) -> i32 {
+        Solution::day(self)
+    }
+
+    fn title(&self) -> &'static str {
+        Solution::title(self)
+    }
+
+    fn parts(&self) -> Vec<&'static str> {
+        let mut parts = vec!["1", "2"];
+        parts.extend(self.variants().iter().map(|v| v.name));
+        parts
+    }
+
+    fn prepare<'a>(&'a self, input: &str) -> Result<Box<dyn Prepared + 'a>, Error> {
+        Ok(Box::new(Parsed {
+            solution: self,
+            input: self.parse(input)?,
+        }))
+    }
+}